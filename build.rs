@@ -0,0 +1,24 @@
+use std::env;
+use std::process::Command;
+use std::str;
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+
+    // `str_eq` loops within a `const fn`, which requires Rust 1.46.
+    match rustc_minor_version() {
+        Some(minor) if minor < 46 => println!("cargo:rustc-cfg=no_const_fn_loops"),
+        _ => {}
+    }
+}
+
+fn rustc_minor_version() -> Option<u32> {
+    let rustc = env::var_os("RUSTC")?;
+    let output = Command::new(rustc).arg("--version").output().ok()?;
+    let version = str::from_utf8(&output.stdout).ok()?;
+    let mut pieces = version.split('.');
+    if pieces.next() != Some("rustc 1") {
+        return None;
+    }
+    pieces.next()?.parse().ok()
+}
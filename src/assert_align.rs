@@ -0,0 +1,168 @@
+/// Asserts that the alignment of a type is less than or equal to a constant.
+///
+/// Unlike [`assert_eq_align!`], this bounds the alignment rather than
+/// requiring it to be equal to that of another type. A message can be given as
+/// the last argument, which is reported if the assertion fails. This requires
+/// Rust 1.57 or later.
+///
+/// Related:
+/// - [`assert_align_lt!`]
+/// - [`assert_align_ge!`]
+/// - [`assert_align_gt!`]
+/// - [`assert_eq_align!`]
+///
+/// # Examples
+///
+/// This is useful for capping alignment, such as when packing types into
+/// buffers with a fixed alignment:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// struct Frame {
+///     id: u32,
+///     data: [u8; 12],
+/// }
+///
+/// assert_align_le!(Frame, 8);
+/// assert_align_le!(u64, 8, "`u64` fits in 8-byte aligned buffers");
+/// ```
+///
+/// The following example fails to compile because the type is over-aligned:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// #[repr(align(16))]
+/// struct Frame([u8; 16]);
+///
+/// assert_align_le!(Frame, 8);
+/// ```
+///
+/// [`assert_align_lt!`]: macro.assert_align_lt.html
+/// [`assert_align_ge!`]: macro.assert_align_ge.html
+/// [`assert_align_gt!`]: macro.assert_align_gt.html
+/// [`assert_eq_align!`]: macro.assert_eq_align.html
+#[macro_export(local_inner_macros)]
+macro_rules! assert_align_le {
+    ($t:ty, $n:expr, $msg:literal $(,)?) => {
+        const _: () = $crate::_core::assert!($crate::_core::mem::align_of::<$t>() <= $n, "{}", $msg);
+    };
+    ($t:ty, $n:expr $(,)?) => {
+        _const_assert!($crate::_core::mem::align_of::<$t>() <= $n);
+    };
+}
+
+/// Asserts that the alignment of a type is less than a constant.
+///
+/// Related:
+/// - [`assert_align_le!`]
+/// - [`assert_align_ge!`]
+/// - [`assert_align_gt!`]
+/// - [`assert_eq_align!`]
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// assert_align_lt!(u16, 4);
+/// ```
+///
+/// The following example fails to compile because the alignment of `u32` is
+/// not less than itself:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// assert_align_lt!(u32, 4);
+/// ```
+///
+/// [`assert_align_le!`]: macro.assert_align_le.html
+/// [`assert_align_ge!`]: macro.assert_align_ge.html
+/// [`assert_align_gt!`]: macro.assert_align_gt.html
+/// [`assert_eq_align!`]: macro.assert_eq_align.html
+#[macro_export(local_inner_macros)]
+macro_rules! assert_align_lt {
+    ($t:ty, $n:expr, $msg:literal $(,)?) => {
+        const _: () = $crate::_core::assert!($crate::_core::mem::align_of::<$t>() < $n, "{}", $msg);
+    };
+    ($t:ty, $n:expr $(,)?) => {
+        _const_assert!($crate::_core::mem::align_of::<$t>() < $n);
+    };
+}
+
+/// Asserts that the alignment of a type is greater than or equal to a constant.
+///
+/// Related:
+/// - [`assert_align_le!`]
+/// - [`assert_align_lt!`]
+/// - [`assert_align_gt!`]
+/// - [`assert_eq_align!`]
+///
+/// # Examples
+///
+/// This can ensure that types declared with `#[repr(align(N))]` keep their
+/// alignment:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// #[repr(align(64))]
+/// struct CacheLine([u8; 64]);
+///
+/// assert_align_ge!(CacheLine, 64, "`CacheLine` must not share cache lines");
+/// ```
+///
+/// The following example fails to compile because bytes have an alignment of
+/// 1:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// assert_align_ge!([u8; 64], 64);
+/// ```
+///
+/// [`assert_align_le!`]: macro.assert_align_le.html
+/// [`assert_align_lt!`]: macro.assert_align_lt.html
+/// [`assert_align_gt!`]: macro.assert_align_gt.html
+/// [`assert_eq_align!`]: macro.assert_eq_align.html
+#[macro_export(local_inner_macros)]
+macro_rules! assert_align_ge {
+    ($t:ty, $n:expr, $msg:literal $(,)?) => {
+        const _: () = $crate::_core::assert!($crate::_core::mem::align_of::<$t>() >= $n, "{}", $msg);
+    };
+    ($t:ty, $n:expr $(,)?) => {
+        _const_assert!($crate::_core::mem::align_of::<$t>() >= $n);
+    };
+}
+
+/// Asserts that the alignment of a type is greater than a constant.
+///
+/// Related:
+/// - [`assert_align_le!`]
+/// - [`assert_align_lt!`]
+/// - [`assert_align_ge!`]
+/// - [`assert_eq_align!`]
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// assert_align_gt!(u32, 1);
+/// ```
+///
+/// The following example fails to compile because `u8` has an alignment of 1:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// assert_align_gt!(u8, 1);
+/// ```
+///
+/// [`assert_align_le!`]: macro.assert_align_le.html
+/// [`assert_align_lt!`]: macro.assert_align_lt.html
+/// [`assert_align_ge!`]: macro.assert_align_ge.html
+/// [`assert_eq_align!`]: macro.assert_eq_align.html
+#[macro_export(local_inner_macros)]
+macro_rules! assert_align_gt {
+    ($t:ty, $n:expr, $msg:literal $(,)?) => {
+        const _: () = $crate::_core::assert!($crate::_core::mem::align_of::<$t>() > $n, "{}", $msg);
+    };
+    ($t:ty, $n:expr $(,)?) => {
+        _const_assert!($crate::_core::mem::align_of::<$t>() > $n);
+    };
+}
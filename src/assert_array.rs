@@ -0,0 +1,51 @@
+/// Asserts that a constant array or slice has the given length.
+///
+/// This is useful for generated lookup tables, which must have an exact number
+/// of entries. The length is checked with [`len`] in a `const` context, so the
+/// value is only referenced and never moved.
+///
+/// The expression must be usable in a `const` context. This works for `const`
+/// arrays and `&'static [T]` slices, but referring to a `static` requires Rust
+/// 1.83 or later. The length of slices that are not `const` is only known at
+/// runtime and thus can't be asserted.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// const TABLE: [u8; 256] = [0; 256];
+/// const NAMES: &[&str] = &["red", "green", "blue"];
+///
+/// assert_array_len!(TABLE, 256);
+/// assert_array_len!(NAMES, 3);
+/// ```
+///
+/// A message can be provided to explain the length, which is reported if the
+/// assertion fails. This requires Rust 1.57 or later:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// # const TABLE: [u8; 256] = [0; 256];
+/// assert_array_len!(TABLE, 256, "the table must have an entry per byte");
+/// ```
+///
+/// The following example fails to compile because the table is missing an
+/// entry:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// const TABLE: [u8; 255] = [0; 255];
+///
+/// assert_array_len!(TABLE, 256);
+/// ```
+///
+/// [`len`]: https://doc.rust-lang.org/std/primitive.slice.html#method.len
+#[macro_export(local_inner_macros)]
+macro_rules! assert_array_len {
+    ($x:expr, $n:expr, $msg:literal $(,)?) => {
+        const _: () = $crate::_core::assert!($x.len() == $n, "{}", $msg);
+    };
+    ($x:expr, $n:expr $(,)?) => {
+        _const_assert!($x.len() == $n);
+    };
+}
@@ -0,0 +1,70 @@
+/// Asserts that a type implements a trait and that an associated `const` of
+/// that implementation satisfies a predicate.
+///
+/// The predicate is written after the name of the `const`, as in
+/// `VALUE == 7`, and is evaluated with [`const_assert!`].
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// trait Id {
+///     const VALUE: u32;
+/// }
+///
+/// struct Foo;
+///
+/// impl Id for Foo {
+///     const VALUE: u32 = 7;
+/// }
+///
+/// assert_assoc_const!(Foo: Id, VALUE == 7);
+/// assert_assoc_const!(Foo: Id, VALUE < 8);
+/// assert_assoc_const!(Foo: Id, VALUE % 2 == 1);
+/// ```
+///
+/// Constants of generic traits can be checked as well:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// trait Codec<T> {
+///     const SIZE: usize;
+/// }
+///
+/// struct Varint;
+///
+/// impl Codec<u64> for Varint {
+///     const SIZE: usize = 10;
+/// }
+///
+/// assert_assoc_const!(Varint: Codec<u64>, SIZE <= 10);
+/// ```
+///
+/// The following example fails to compile because the value differs:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// # trait Id { const VALUE: u32; }
+/// # struct Foo;
+/// # impl Id for Foo { const VALUE: u32 = 7; }
+/// assert_assoc_const!(Foo: Id, VALUE == 8);
+/// ```
+///
+/// Likewise, this fails to compile because the trait is not implemented:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// # trait Id { const VALUE: u32; }
+/// struct Bar;
+///
+/// assert_assoc_const!(Bar: Id, VALUE == 7);
+/// ```
+///
+/// [`const_assert!`]: macro.const_assert.html
+#[macro_export(local_inner_macros)]
+macro_rules! assert_assoc_const {
+    ($t:ty: $trait:path, $c:ident $($pred:tt)+) => {
+        assert_impl_all!($t: $trait);
+        _const_assert!(<$t as $trait>::$c $($pred)+);
+    };
+}
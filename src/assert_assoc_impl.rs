@@ -0,0 +1,55 @@
+/// Asserts that a type implements a trait and that an associated type of that
+/// implementation implements _all_ of the given traits.
+///
+/// This is shorthand for asserting both `T: Trait` and
+/// `<T as Trait>::Assoc: ...` with [`assert_impl_all!`]. It is useful for types
+/// whose associated types are tedious to name, such as iterators built from
+/// adapters.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// use std::collections::hash_map::{HashMap, IntoIter};
+///
+/// type Entries = IntoIter<String, Vec<u8>>;
+///
+/// assert_assoc_impl!(Entries: Iterator, Item: Send, Sync, Clone);
+/// assert_assoc_impl!(HashMap<u8, u8>: IntoIterator, IntoIter: Send);
+/// ```
+///
+/// Associated types of generic traits can be checked as well:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// use std::ops::Add;
+///
+/// assert_assoc_impl!(u32: Add<u32>, Output: Copy + Ord);
+/// ```
+///
+/// The following example fails to compile because [`Rc`] is not [`Send`]:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// use std::rc::Rc;
+///
+/// assert_assoc_impl!(std::vec::IntoIter<Rc<u8>>: Iterator, Item: Send);
+/// ```
+///
+/// Likewise, this fails to compile because the trait is not implemented:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// assert_assoc_impl!(u8: Iterator, Item: Send);
+/// ```
+///
+/// [`assert_impl_all!`]: macro.assert_impl_all.html
+/// [`Rc`]: https://doc.rust-lang.org/std/rc/struct.Rc.html
+/// [`Send`]: https://doc.rust-lang.org/std/marker/trait.Send.html
+#[macro_export(local_inner_macros)]
+macro_rules! assert_assoc_impl {
+    ($t:ty: $trait:path, $assoc:ident: $($rest:tt)+) => {
+        assert_impl_all!($t: $trait);
+        assert_impl_all!(<$t as $trait>::$assoc: $($rest)+);
+    };
+}
@@ -26,6 +26,16 @@
 ///             "Must exclusively use MySQL or MongoDB as database back-end");
 /// ```
 ///
+/// The message may also be placed first, which can read better when the
+/// configuration is long:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// # #[cfg(target_pointer_width = "0")] // Impossible
+/// assert_cfg!("Requires `std` and an allocator",
+///             all(feature = "std", not(feature = "no_alloc")));
+/// ```
+///
 /// Some configurations are impossible. For example, we can't be compiling for
 /// both macOS _and_ Windows simultaneously:
 ///
@@ -38,6 +48,10 @@
 #[macro_export]
 macro_rules! assert_cfg {
     () => {};
+    ($msg:literal, $($cfg:meta)+ $(,)?) => {
+        #[cfg(not($($cfg)+))]
+        compile_error!($msg);
+    };
     ($($cfg:meta)+, $msg:expr $(,)?) => {
         #[cfg(not($($cfg)+))]
         compile_error!($msg);
@@ -47,3 +61,90 @@ macro_rules! assert_cfg {
         compile_error!(concat!("Cfg does not pass: ", stringify!($($cfg)*)));
     };
 }
+
+/// Asserts that a language or library capability was detected by the build
+/// script.
+///
+/// Rather than hardcoding the toolchain versions that support something, a
+/// [build script] can try compiling a small probe for it and set a
+/// `feature_available = "..."` [configuration] when the probe succeeds. The
+/// crate then fails to compile with a clear message if the capability is
+/// missing.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// # #[cfg(feature_available = "const_str_eq")] // Not set when testing docs
+/// assert_feature_available!("const_str_eq", "const_panic");
+/// ```
+///
+/// This beats raw version checks in a few ways:
+///
+/// - It is correct for nightly and beta toolchains, as well as for compilers
+///   that backport or lag behind features.
+/// - It can't be out of date with the version that stabilized something.
+/// - It documents _what_ is required rather than a number.
+///
+/// # Build Script
+///
+/// The following build script can be copied and extended with more probes. It
+/// compiles each probe with the same compiler as the crate being built:
+///
+/// ```no_run
+/// // build.rs
+/// use std::env;
+/// use std::path::Path;
+/// use std::process::{Command, Stdio};
+///
+/// const PROBES: &[(&str, &str)] = &[
+///     ("const_str_eq", "const _: bool = matches!(\"a\".as_bytes(), b\"a\");"),
+///     ("const_panic", "const _: () = assert!(true);"),
+/// ];
+///
+/// fn main() {
+///     let rustc = env::var_os("RUSTC").unwrap_or("rustc".into());
+///     let out_dir = env::var_os("OUT_DIR").unwrap();
+///
+///     for (name, code) in PROBES {
+///         let probe = Path::new(&out_dir).join(format!("probe_{}.rs", name));
+///         std::fs::write(&probe, code).unwrap();
+///
+///         let ok = Command::new(&rustc)
+///             .args(&["--crate-type=lib", "--emit=metadata", "--out-dir"])
+///             .arg(&out_dir)
+///             .arg(&probe)
+///             .stderr(Stdio::null())
+///             .status()
+///             .map_or(false, |status| status.success());
+///
+///         println!("cargo:rustc-check-cfg=cfg(feature_available, values(\"{}\"))", name);
+///         if ok {
+///             println!("cargo:rustc-cfg=feature_available=\"{}\"", name);
+///         }
+///     }
+/// }
+/// ```
+///
+/// The following example fails to compile because no build script has
+/// detected the capability:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// assert_feature_available!("const_str_eq");
+/// ```
+///
+/// [build script]: https://doc.rust-lang.org/cargo/reference/build-scripts.html
+/// [configuration]: https://doc.rust-lang.org/reference/conditional-compilation.html
+#[macro_export]
+macro_rules! assert_feature_available {
+    ($($name:literal),+ $(,)?) => {
+        $(
+            #[cfg(not(feature_available = $name))]
+            compile_error!(concat!(
+                "Feature `", $name, "` is not available; ",
+                "expected `feature_available = \"", $name, "\"` to be set by the build script",
+            ));
+        )+
+    };
+}
@@ -0,0 +1,43 @@
+/// Asserts that expressions can be evaluated in a `const` context.
+///
+/// This is useful for ensuring that helper functions stay `const fn`, since
+/// removing the qualifier would otherwise only break users that happen to call
+/// them from a `const` context.
+///
+/// Each expression is evaluated at compile time and its result is discarded
+/// without being dropped, which requires Rust 1.46 or later. As such, the
+/// arguments must be constants too, and the evaluation must not panic.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// const fn align_up(value: usize, align: usize) -> usize {
+///     (value + align - 1) & !(align - 1)
+/// }
+///
+/// assert_const_fn!(align_up(3, 8), u8::max_value(), String::new());
+/// ```
+///
+/// The following example fails to compile because `align_up` is not a
+/// `const fn`:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// fn align_up(value: usize, align: usize) -> usize {
+///     (value + align - 1) & !(align - 1)
+/// }
+///
+/// assert_const_fn!(align_up(3, 8));
+/// ```
+#[macro_export]
+macro_rules! assert_const_fn {
+    ($($x:expr),+ $(,)?) => {
+        // `forget` ensures values are not dropped, which may not be possible
+        // in a `const` context.
+        $(
+            #[allow(unknown_lints, forgetting_copy_types, clippy::forget_non_drop)]
+            const _: () = $crate::_core::mem::forget($x);
+        )+
+    };
+}
@@ -0,0 +1,88 @@
+/// Asserts that types are [`Copy`].
+///
+/// This is shorthand for `assert_impl_all!(T: Copy)` for each type. It is
+/// useful for catching types that are assumed to be [`Copy`] but are only
+/// [`Clone`].
+///
+/// See [`assert_clone_not_copy!`] for asserting that a type is only [`Clone`].
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// #[derive(Clone, Copy)]
+/// struct Point {
+///     x: i32,
+///     y: i32,
+/// }
+///
+/// assert_copy!(Point, u32, &'static str, Option<char>);
+/// ```
+///
+/// The following example fails to compile because [`String`] is only
+/// [`Clone`]:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// assert_copy!(String);
+/// ```
+///
+/// [`assert_clone_not_copy!`]: macro.assert_clone_not_copy.html
+/// [`Clone`]: https://doc.rust-lang.org/std/clone/trait.Clone.html
+/// [`Copy`]: https://doc.rust-lang.org/std/marker/trait.Copy.html
+/// [`String`]: https://doc.rust-lang.org/std/string/struct.String.html
+#[macro_export(local_inner_macros)]
+macro_rules! assert_copy {
+    ($($t:ty),+ $(,)?) => {
+        $(assert_impl_all!($t: $crate::_core::marker::Copy);)+
+    };
+}
+
+/// Asserts that types are [`Clone`] but **not** [`Copy`].
+///
+/// This is useful for types that own resources, where copying would be a bug
+/// but explicit cloning is still allowed.
+///
+/// See [`assert_copy!`] for achieving the opposite effect.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// #[derive(Clone)]
+/// struct Buffer {
+///     data: Vec<u8>,
+/// }
+///
+/// assert_clone_not_copy!(Buffer, String, Vec<u8>);
+/// ```
+///
+/// The following example fails to compile because `u32` is [`Copy`]:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// assert_clone_not_copy!(u32);
+/// ```
+///
+/// Types must still be [`Clone`]. This fails to compile because the type
+/// implements neither:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// struct Handle(u32);
+///
+/// assert_clone_not_copy!(Handle);
+/// ```
+///
+/// [`assert_copy!`]: macro.assert_copy.html
+/// [`Clone`]: https://doc.rust-lang.org/std/clone/trait.Clone.html
+/// [`Copy`]: https://doc.rust-lang.org/std/marker/trait.Copy.html
+#[macro_export(local_inner_macros)]
+macro_rules! assert_clone_not_copy {
+    ($($t:ty),+ $(,)?) => {
+        $(
+            assert_impl_all!($t: $crate::_core::clone::Clone);
+            assert_not_impl_any!($t: $crate::_core::marker::Copy);
+        )+
+    };
+}
@@ -0,0 +1,83 @@
+/// Asserts that the [`Default`] value of a type equals the given constant.
+///
+/// This asserts that the type implements [`Default`] and [`PartialEq`], and
+/// then compares `T::default()` with the expected value within a `const`. It
+/// is useful for configuration types, whose defaults are often documented and
+/// should not change by accident.
+///
+/// # Limitations
+///
+/// Calling trait methods in a `const` requires both traits to be implemented
+/// as `const`, which is not yet stable. As such, this requires a nightly
+/// compiler and the following features to be enabled in the crate using it,
+/// even for types in the standard library:
+///
+/// ```ignore
+/// #![feature(const_trait_impl, const_default, const_cmp)]
+/// ```
+///
+/// Types must implement both traits with `impl const`, since derived
+/// implementations are not `const`, and not all implementations in the
+/// standard library are `const` yet, such as those of tuples. Types that need
+/// to be dropped can't be compared, since dropping is not `const` either.
+///
+/// # Examples
+///
+/// ```ignore
+/// #![feature(const_trait_impl, const_default, const_cmp)]
+///
+/// struct Config {
+///     retries: u32,
+///     verbose: bool,
+/// }
+///
+/// impl const Default for Config {
+///     fn default() -> Self {
+///         Config { retries: 3, verbose: false }
+///     }
+/// }
+///
+/// impl const PartialEq for Config {
+///     fn eq(&self, other: &Self) -> bool {
+///         self.retries == other.retries && self.verbose == other.verbose
+///     }
+/// }
+///
+/// assert_default_eq!(Config, Config { retries: 3, verbose: false });
+/// assert_default_eq!(u32, 0);
+/// assert_default_eq!(Option<char>, None);
+/// ```
+///
+/// The following example fails to compile because the default of [`bool`] is
+/// `false`:
+///
+/// ```ignore
+/// #![feature(const_trait_impl, const_default, const_cmp)]
+///
+/// assert_default_eq!(bool, true);
+/// ```
+///
+/// This reports:
+///
+/// ```txt
+/// error[E0080]: evaluation panicked: `bool::default()` does not equal `true`
+/// ```
+///
+/// [`Default`]: https://doc.rust-lang.org/std/default/trait.Default.html
+/// [`PartialEq`]: https://doc.rust-lang.org/std/cmp/trait.PartialEq.html
+/// [`bool`]: https://doc.rust-lang.org/std/primitive.bool.html
+#[macro_export(local_inner_macros)]
+macro_rules! assert_default_eq {
+    ($t:ty, $expected:expr $(,)?) => {
+        assert_impl_all!($t: $crate::_core::default::Default, $crate::_core::cmp::PartialEq);
+
+        const _: () = $crate::_core::assert!(
+            <$t as $crate::_core::default::Default>::default() == $expected,
+            "{}",
+            $crate::_core::concat!(
+                "`", $crate::_core::stringify!($t), "::default()` does not equal `",
+                $crate::_core::stringify!($expected), "`",
+            ),
+        );
+    };
+}
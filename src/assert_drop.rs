@@ -0,0 +1,122 @@
+/// Asserts that types do not need to be dropped.
+///
+/// This ensures that dropping a value of the type would do nothing, which
+/// makes it safe to skip with [`mem::forget`] or to place in a `union`. It
+/// relies on [`mem::needs_drop`], which may be conservative and report that a
+/// type needs to be dropped even if it doesn't.
+///
+/// This is stricter than [`assert_no_drop_impl!`], which only checks that the
+/// type itself does not implement [`Drop`], regardless of its fields.
+///
+/// # Examples
+///
+/// Plain data types never need to be dropped:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// #[derive(Clone, Copy)]
+/// struct MyPod {
+///     id: u32,
+///     flags: u8,
+/// }
+///
+/// assert_no_drop!(MyPod, u8, (), &'static str);
+/// ```
+///
+/// A message can be provided after a `;` to explain why the types must not need
+/// to be dropped. It is reported if the assertion fails, which requires Rust
+/// 1.57 or later:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// # #[derive(Clone, Copy)] struct MyPod;
+/// assert_no_drop!(MyPod; "`MyPod` values are forgotten after use");
+/// ```
+///
+/// The following example fails to compile because [`String`] frees its buffer
+/// when dropped:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// struct Named {
+///     id: u32,
+///     name: String,
+/// }
+///
+/// assert_no_drop!(Named);
+/// ```
+///
+/// [`mem::forget`]: https://doc.rust-lang.org/std/mem/fn.forget.html
+/// [`mem::needs_drop`]: https://doc.rust-lang.org/std/mem/fn.needs_drop.html
+/// [`String`]: https://doc.rust-lang.org/std/string/struct.String.html
+/// [`Drop`]: https://doc.rust-lang.org/std/ops/trait.Drop.html
+/// [`assert_no_drop_impl!`]: macro.assert_no_drop_impl.html
+#[macro_export(local_inner_macros)]
+macro_rules! assert_no_drop {
+    ($($t:ty),+ ; $msg:literal $(,)?) => {
+        $(const _: () = $crate::_core::assert!(!$crate::_core::mem::needs_drop::<$t>(), "{}", $msg);)+
+    };
+    ($($t:ty),+ $(,)?) => {
+        $(_const_assert!(!$crate::_core::mem::needs_drop::<$t>());)+
+    };
+}
+
+/// Asserts that types do not implement [`Drop`] themselves.
+///
+/// Unlike [`assert_no_drop!`], this does not consider the fields of a type. A
+/// struct with a [`String`] field needs to be dropped, yet passes this check
+/// as long as there is no `impl Drop` for the struct itself. This is useful for
+/// types that are destructured or moved out of, which is not allowed for types
+/// that implement [`Drop`].
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// struct Named {
+///     id: u32,
+///     name: String,
+/// }
+///
+/// assert_no_drop_impl!(Named, String, u8);
+///
+/// // `Named` still needs to be dropped because of its `String`.
+/// const_assert!(std::mem::needs_drop::<Named>());
+/// ```
+///
+/// The following example fails to compile because `Guard` implements [`Drop`]:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// struct Guard;
+///
+/// impl Drop for Guard {
+///     fn drop(&mut self) {}
+/// }
+///
+/// assert_no_drop_impl!(Guard);
+/// ```
+///
+/// Standard library types that free memory, such as [`Vec`], implement
+/// [`Drop`] directly:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// assert_no_drop_impl!(Vec<u8>);
+/// ```
+///
+/// [`Drop`]: https://doc.rust-lang.org/std/ops/trait.Drop.html
+/// [`String`]: https://doc.rust-lang.org/std/string/struct.String.html
+/// [`Vec`]: https://doc.rust-lang.org/std/vec/struct.Vec.html
+/// [`assert_no_drop!`]: macro.assert_no_drop.html
+#[macro_export(local_inner_macros)]
+macro_rules! assert_no_drop_impl {
+    ($($t:ty),+ $(,)?) => {
+        $(
+            #[allow(unknown_lints, drop_bounds)]
+            const _: () = {
+                assert_not_impl_any!($t: $crate::_core::ops::Drop);
+            };
+        )+
+    };
+}
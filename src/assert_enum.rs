@@ -0,0 +1,246 @@
+/// Asserts that an `enum` has exactly the given variants.
+///
+/// This is useful for ensuring that code matching on an `enum` from another
+/// crate is updated whenever variants are added or removed.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// enum Color {
+///     Red,
+///     Green,
+///     Blue,
+/// }
+///
+/// assert_enum_variants!(Color: Red, Green, Blue);
+/// ```
+///
+/// Variants that hold data can optionally be followed by `(..)` or `{ .. }`:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// enum Message {
+///     Quit,
+///     Move { x: i32, y: i32 },
+///     Write(String),
+/// }
+///
+/// assert_enum_variants!(Message: Quit, Move { .. }, Write(..));
+/// assert_enum_variants!(Option<u8>: Some, None);
+/// ```
+///
+/// The following example fails to compile because `Blue` is not listed:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// enum Color {
+///     Red,
+///     Green,
+///     Blue,
+/// }
+///
+/// assert_enum_variants!(Color: Red, Green);
+/// ```
+///
+/// Likewise, `enum`s marked [`#[non_exhaustive]`][non_exhaustive] in other
+/// crates can never pass, since they may gain variants at any time.
+///
+/// [non_exhaustive]: https://doc.rust-lang.org/reference/attributes/type_system.html#the-non_exhaustive-attribute
+#[macro_export]
+macro_rules! assert_enum_variants {
+    ($t:ty: $($v:ident $(( .. ))? $({ .. })?),+ $(,)?) => {
+        const _: fn() = || {
+            // Variants can't be named through a `ty`, but can through an alias.
+            type Enum = $t;
+
+            // An exhaustive `match` fails for any variant not listed, and
+            // listing a variant that doesn't exist fails as well.
+            #[allow(unreachable_code, unused_variables)]
+            {
+                let value: &Enum = loop {};
+                match *value {
+                    $(Enum::$v { .. } => {})+
+                }
+            }
+
+            // Listing a variant twice defines an item of the same name twice.
+            // An unreachable `match` arm would only be a lint, which is not
+            // reported for code from other crates' macros.
+            #[allow(dead_code, non_camel_case_types)]
+            {
+                $(struct $v;)+
+            }
+        };
+    };
+}
+
+/// Asserts that the variants of a field-less `enum` have the given
+/// discriminant values.
+///
+/// This is useful for `#[repr(C)]` or `#[repr(u8)]` types shared over [FFI],
+/// where the integer value of each variant is part of the interface. Each
+/// variant is cast to [`i128`] and compared against the expected value, so
+/// negative discriminants work too.
+///
+/// Only the listed variants are checked. To also ensure that no variants are
+/// missing, pair this with [`assert_enum_variants!`].
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// #[repr(u8)]
+/// enum Status {
+///     Ok = 0,
+///     Err = 1,
+/// }
+///
+/// assert_discriminants!(Status: Ok = 0, Err = 1);
+/// ```
+///
+/// Explicit discriminants don't need to be sequential, and implicit ones
+/// continue counting from the previous variant:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// #[repr(i32)]
+/// enum Code {
+///     Invalid = -1,
+///     Start = 10,
+///     Next,
+///     End = 0x100,
+/// }
+///
+/// assert_discriminants!(Code: Invalid = -1, Start = 10, Next = 11, End = 256);
+/// ```
+///
+/// The following example fails to compile because `Err` has a discriminant of
+/// 2:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// #[repr(C)]
+/// enum Status {
+///     Ok = 0,
+///     Err = 2,
+/// }
+///
+/// assert_discriminants!(Status: Ok = 0, Err = 1);
+/// ```
+///
+/// [FFI]: https://en.wikipedia.org/wiki/Foreign_function_interface
+/// [`i128`]: https://doc.rust-lang.org/std/primitive.i128.html
+/// [`assert_enum_variants!`]: macro.assert_enum_variants.html
+#[macro_export(local_inner_macros)]
+macro_rules! assert_discriminants {
+    ($t:ty: $($v:ident = $d:expr),+ $(,)?) => {
+        const _: () = {
+            // Variants can't be named through a `ty`, but can through an alias.
+            type Enum = $t;
+
+            $(_const_assert!(Enum::$v as i128 == $d);)+
+        };
+    };
+}
+
+/// Asserts that an `enum` has the given number of variants.
+///
+/// On stable Rust, the variants must be listed as with
+/// [`assert_enum_variants!`], after the count and a `;`. This ensures that the
+/// list is exhaustive and that it is as long as expected:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// enum Color {
+///     Red,
+///     Green,
+///     Blue,
+/// }
+///
+/// assert_variant_count!(Color, 3; Red, Green, Blue);
+/// ```
+///
+/// The following example fails to compile because `Color` has 3 variants:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// # enum Color { Red, Green, Blue }
+/// assert_variant_count!(Color, 4; Red, Green, Blue);
+/// ```
+///
+/// Each variant may only be listed once, so that a variant can't make up for
+/// one that is missing. The following example fails to compile because `Red`
+/// is listed twice:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// # enum Color { Red, Green }
+/// assert_variant_count!(Color, 3; Red, Red, Green);
+/// ```
+///
+/// # Nightly
+///
+/// With the `nightly` feature flag, the variants may also be left out. The
+/// count is then checked with [`mem::variant_count`], which is unstable and so
+/// requires a nightly compiler. The crate that calls this does not need to
+/// enable any unstable features itself:
+///
+/// ```ignore
+/// enum Color {
+///     Red,
+///     Green,
+///     Blue,
+/// }
+///
+/// assert_variant_count!(Color, 3);
+/// assert_variant_count!(Option<u8>, 2);
+/// ```
+///
+/// Without the feature flag, this form fails to compile with a message asking
+/// for either the flag or the variants.
+///
+/// [`assert_enum_variants!`]: macro.assert_enum_variants.html
+/// [`mem::variant_count`]: https://doc.rust-lang.org/std/mem/fn.variant_count.html
+#[macro_export(local_inner_macros)]
+macro_rules! assert_variant_count {
+    ($t:ty, $n:expr; $($v:ident $(( .. ))? $({ .. })?),+ $(,)?) => {
+        assert_enum_variants!($t: $($v),+);
+        _const_assert!([$($crate::_core::stringify!($v)),+].len() == $n);
+    };
+    ($t:ty, $n:expr $(,)?) => {
+        _assert_variant_count!($t, $n);
+    };
+}
+
+/// Emits `assert_variant_count!` without a list of variants.
+///
+/// This is defined here rather than via `#[cfg]` within the expansion, which
+/// would check the features of the calling crate instead.
+#[doc(hidden)]
+#[cfg(feature = "nightly")]
+#[macro_export(local_inner_macros)]
+macro_rules! _assert_variant_count {
+    ($t:ty, $n:expr) => {
+        _const_assert!($crate::_variant_count::<$t>() == $n);
+    };
+}
+
+/// Wraps `mem::variant_count`, so that calling crates don't need to enable the
+/// unstable feature themselves.
+#[doc(hidden)]
+#[cfg(feature = "nightly")]
+pub const fn _variant_count<T>() -> usize {
+    core::mem::variant_count::<T>()
+}
+
+#[doc(hidden)]
+#[cfg(not(feature = "nightly"))]
+#[macro_export(local_inner_macros)]
+macro_rules! _assert_variant_count {
+    ($t:ty, $n:expr) => {
+        $crate::_core::compile_error!(
+            "`assert_variant_count!` requires the `nightly` feature flag, or the variants to be listed after a `;`"
+        );
+    };
+}
@@ -19,6 +19,14 @@
 /// assert_eq_align!([i32; 4], i32);
 /// ```
 ///
+/// The following example fails to compile because `u8` and `u64` have different
+/// alignments:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// assert_eq_align!(u8, u64);
+/// ```
+///
 /// The following example fails to compile because `i32x4` explicitly has 4
 /// times the alignment as `[i32; 4]`:
 ///
@@ -31,10 +39,47 @@
 /// assert_eq_align!(i32x4, [i32; 4]);
 /// ```
 ///
+/// # Runtime Assertions
+///
+/// With the `runtime` feature flag, this becomes a [`debug_assert_eq!`] of the
+/// alignments instead. See the [crate docs][runtime] for details.
+///
 /// [FFI]: https://en.wikipedia.org/wiki/Foreign_function_interface
-#[macro_export]
+/// [`debug_assert_eq!`]: https://doc.rust-lang.org/std/macro.debug_assert_eq.html
+/// [runtime]: index.html#runtime-assertions
+#[macro_export(local_inner_macros)]
 macro_rules! assert_eq_align {
     ($x:ty, $($xs:ty),+ $(,)?) => {
+        _runtime_assert_eq_align!($x, $($xs),+);
+    };
+}
+
+/// Emits `assert_eq_align!`, which the `runtime` feature flag turns into a
+/// `debug_assert_eq!`.
+#[doc(hidden)]
+#[cfg(not(feature = "runtime"))]
+#[macro_export(local_inner_macros)]
+macro_rules! _runtime_assert_eq_align {
+    ($x:ty, $($xs:ty),+) => {
+        _assert_eq_align!($x, $($xs),+);
+    };
+}
+
+#[doc(hidden)]
+#[cfg(feature = "runtime")]
+#[macro_export(local_inner_macros)]
+macro_rules! _runtime_assert_eq_align {
+    ($x:ty, $($xs:ty),+) => {
+        debug_assert_eq_align!($x, $($xs),+);
+    };
+}
+
+/// Asserts `assert_eq_align!` at compile-time regardless of the `runtime`
+/// feature flag, for use by other assertions.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _assert_eq_align {
+    ($x:ty, $($xs:ty),+) => {
         const _: fn() = || {
             // Assigned instance must match the annotated type or else it will
             // fail to compile
@@ -0,0 +1,63 @@
+/// Asserts that types implement [`PartialEq`], [`Eq`], and [`Hash`] together.
+///
+/// Types that are compared with [`Eq`] are often used as keys in a
+/// [`HashMap`] or [`HashSet`], which also requires [`Hash`]. Deriving [`Eq`]
+/// without [`Hash`] is an easy mistake to make, so a missing [`Hash`] is
+/// reported with a message about it rather than the default one. This makes
+/// use of the [custom messages] of [`assert_impl_all!`] and thus requires Rust
+/// 1.78 or later.
+///
+/// This is shorthand for `assert_impl_all!(T: PartialEq, Eq, Hash)` for each
+/// type. It does not check that equal values hash equally, which is up to the
+/// implementations.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// #[derive(PartialEq, Eq, Hash)]
+/// struct UserId(u64);
+///
+/// assert_eq_hash!(UserId, String, (u8, char), Option<UserId>);
+/// ```
+///
+/// The following example fails to compile because `Key` derives [`Eq`] but
+/// not [`Hash`]:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// #[derive(PartialEq, Eq)]
+/// struct Key(u32);
+///
+/// assert_eq_hash!(Key);
+/// ```
+///
+/// Floating-point numbers are only [`PartialEq`], so they are rejected too:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// assert_eq_hash!(f64);
+/// ```
+///
+/// [custom messages]: macro.assert_impl_all.html#custom-messages
+/// [`assert_impl_all!`]: macro.assert_impl_all.html
+/// [`PartialEq`]: https://doc.rust-lang.org/std/cmp/trait.PartialEq.html
+/// [`Eq`]: https://doc.rust-lang.org/std/cmp/trait.Eq.html
+/// [`Hash`]: https://doc.rust-lang.org/std/hash/trait.Hash.html
+/// [`HashMap`]: https://doc.rust-lang.org/std/collections/struct.HashMap.html
+/// [`HashSet`]: https://doc.rust-lang.org/std/collections/struct.HashSet.html
+#[macro_export(local_inner_macros)]
+macro_rules! assert_eq_hash {
+    ($($t:ty),+ $(,)?) => {
+        $(
+            assert_impl_all!(
+                $t: $crate::_core::cmp::PartialEq, $crate::_core::cmp::Eq;
+                "`{Self}` must implement `PartialEq` and `Eq` for `assert_eq_hash!`"
+            );
+            assert_impl_all!(
+                $t: $crate::_core::hash::Hash;
+                "`{Self}` does not implement `Hash`, which is expected of types that are `Eq`"
+            );
+        )+
+    };
+}
@@ -19,6 +19,21 @@
 /// assert_eq_size!([u8; 4], (u16, u16), u32);
 /// ```
 ///
+/// This is especially useful for [FFI], where a type must occupy the same
+/// number of bytes as its foreign counterpart:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// #[repr(C)]
+/// struct MyStruct {
+///     id: u32,
+///     flags: u32,
+///     data: u64,
+/// }
+///
+/// assert_eq_size!(MyStruct, [u8; 16]);
+/// ```
+///
 /// The following example fails to compile because `u32` has 4 times the size of
 /// `u8`:
 ///
@@ -27,12 +42,54 @@
 /// assert_eq_size!(u32, u8);
 /// ```
 ///
+/// # Runtime Assertions
+///
+/// With the `runtime` feature flag, this becomes a [`debug_assert_eq!`] of the
+/// sizes instead. See the [crate docs][runtime] for details.
+///
 /// [`usize`]: https://doc.rust-lang.org/std/primitive.usize.html
 /// [`u64`]: https://doc.rust-lang.org/std/primitive.u64.html
 /// [`u32`]: https://doc.rust-lang.org/std/primitive.u32.html
-#[macro_export]
+/// [FFI]: https://en.wikipedia.org/wiki/Foreign_function_interface
+/// [`debug_assert_eq!`]: https://doc.rust-lang.org/std/macro.debug_assert_eq.html
+/// [runtime]: index.html#runtime-assertions
+#[macro_export(local_inner_macros)]
 macro_rules! assert_eq_size {
+    // Labels were required on stable Rust before `const _` and are ignored.
+    ($label:ident; $($rest:tt)*) => {
+        assert_eq_size!($($rest)*);
+    };
     ($x:ty, $($xs:ty),+ $(,)?) => {
+        _runtime_assert_eq_size!($x, $($xs),+);
+    };
+}
+
+/// Emits `assert_eq_size!`, which the `runtime` feature flag turns into a
+/// `debug_assert_eq!`.
+#[doc(hidden)]
+#[cfg(not(feature = "runtime"))]
+#[macro_export(local_inner_macros)]
+macro_rules! _runtime_assert_eq_size {
+    ($x:ty, $($xs:ty),+) => {
+        _assert_eq_size!($x, $($xs),+);
+    };
+}
+
+#[doc(hidden)]
+#[cfg(feature = "runtime")]
+#[macro_export(local_inner_macros)]
+macro_rules! _runtime_assert_eq_size {
+    ($x:ty, $($xs:ty),+) => {
+        debug_assert_eq_size!($x, $($xs),+);
+    };
+}
+
+/// Asserts `assert_eq_size!` at compile-time regardless of the `runtime`
+/// feature flag, for use by other assertions.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _assert_eq_size {
+    ($x:ty, $($xs:ty),+) => {
         const _: fn() = || {
             $(let _ = $crate::_core::mem::transmute::<$x, $xs>;)+
         };
@@ -54,6 +111,17 @@ macro_rules! assert_eq_size {
 /// }
 /// ```
 ///
+/// Raw pointers work as well. The pointers are only ever read from within a
+/// closure that is never called, so they are not required to be valid:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// fn cast(p: *const u64, q: *mut [u8; 8]) {
+///     assert_eq_size_ptr!(p, q);
+///     // ...
+/// }
+/// ```
+///
 /// The following example fails to compile because byte arrays of different
 /// lengths have different sizes:
 ///
@@ -71,6 +139,7 @@ macro_rules! assert_eq_size {
 /// ];
 ///
 /// assert_eq_size_ptr!(BYTES, TABLE);
+/// # }
 /// ```
 #[macro_export]
 macro_rules! assert_eq_size_ptr {
@@ -88,7 +157,8 @@ macro_rules! assert_eq_size_ptr {
 /// Asserts that values are equal in size.
 ///
 /// This macro doesn't consume its arguments and thus works for
-/// non-[`Clone`]able values.
+/// non-[`Clone`]able values. The values are only referenced, so expressions are
+/// never evaluated for their side effects.
 ///
 /// # Examples
 ///
@@ -105,6 +175,19 @@ macro_rules! assert_eq_size_ptr {
 /// # }
 /// ```
 ///
+/// Because types don't need to be named, this is handy for closures and other
+/// types that can't be written out:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions;
+/// # fn main() {
+/// let value = 1u32;
+/// let closure = move || value + 1;
+///
+/// assert_eq_size_val!(closure, value);
+/// # }
+/// ```
+///
 /// Even though both values are 0, they are of types with different sizes:
 ///
 /// ```compile_fail
@@ -39,6 +39,28 @@
 /// assert_fields!(Data::Val: id, bytes);
 /// ```
 ///
+/// Fields of tuple structs and tuple variants are referred to by index:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// struct Rgba(u8, u8, u8, u8);
+///
+/// assert_fields!(Rgba: 0, 1, 2, 3);
+/// ```
+///
+/// The following example fails to compile because `Rgba` only has 4 fields:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// struct Rgba(u8, u8, u8, u8);
+///
+/// assert_fields!(Rgba: 4);
+/// ```
+///
+/// Fields are only ever matched against, never constructed. This means that
+/// types marked [`#[non_exhaustive]`][non_exhaustive] from other crates work
+/// too.
+///
 /// The following example fails to compile because [`Range`] does not have a field named `middle`:
 ///
 /// ```compile_fail
@@ -49,24 +71,26 @@
 /// ```
 ///
 /// [`Range`]: https://doc.rust-lang.org/std/ops/struct.Range.html
-#[macro_export]
+/// [non_exhaustive]: https://doc.rust-lang.org/reference/attributes/type_system.html#the-non_exhaustive-attribute
+#[macro_export(local_inner_macros)]
 macro_rules! assert_fields {
-    ($t:ident::$v:ident: $($f:ident),+) => {
+    // Labels were required on stable Rust before `const _` and are ignored.
+    ($label:ident; $($rest:tt)*) => {
+        assert_fields!($($rest)*);
+    };
+    ($t:path: $($f:tt),+ $(,)?) => {
         #[allow(unknown_lints, unneeded_field_pattern)]
         const _: fn() = || {
-            #[allow(dead_code, unreachable_patterns)]
-            fn assert(value: $t) {
-                match value {
-                    $($t::$v { $f: _, .. } => {},)+
+            // Matching through a reference allows for unsized types and works
+            // the same for both structs and enum variants.
+            #[allow(unreachable_code, unreachable_patterns, unused_variables)]
+            {
+                let value: &_ = loop {};
+                $(match *value {
+                    $t { $f: _, .. } => {}
                     _ => {}
-                }
+                })+
             }
         };
     };
-    ($t:path: $($f:ident),+) => {
-        #[allow(unknown_lints, unneeded_field_pattern)]
-        const _: fn() = || {
-            $(let $t { $f: _, .. };)+
-        };
-    };
 }
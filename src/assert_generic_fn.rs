@@ -0,0 +1,60 @@
+/// Asserts that the body of a generic function type-checks for all of its
+/// generic parameters.
+///
+/// This is useful for ensuring that a generic call keeps compiling given only
+/// the bounds that callers are expected to provide, such as checking that
+/// `foo::<T>()` is valid for any `T: Clone`. A concrete type that happens to
+/// satisfy more traits can't hide a missing bound this way.
+///
+/// The function is given a name, but is never called and never monomorphized.
+/// It may take parameters and have a `where` clause.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// fn dup<T: Clone>(x: &T) -> (T, T) {
+///     (x.clone(), x.clone())
+/// }
+///
+/// assert_generic_fn!(fn<T: Clone>() {
+///     let _ = dup::<T>;
+/// });
+///
+/// assert_generic_fn!(fn<T>(x: &T) where T: Clone + Default {
+///     let (_, _) = dup(x);
+///     let _ = T::default();
+/// });
+/// ```
+///
+/// The following example fails to compile because `Clone` does not guarantee
+/// the `Copy` bound required by `dup_copy`:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// fn dup_copy<T: Copy>(x: T) -> (T, T) {
+///     (x, x)
+/// }
+///
+/// assert_generic_fn!(fn<T: Clone>(x: T) {
+///     dup_copy(x);
+/// });
+/// ```
+///
+/// Neither can the body call methods not provided by the bounds:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// assert_generic_fn!(fn<T: Clone>(x: T) {
+///     x.to_string();
+/// });
+/// ```
+#[macro_export]
+macro_rules! assert_generic_fn {
+    (fn $($rest:tt)+) => {
+        const _: fn() = || {
+            #[allow(dead_code, unused_variables)]
+            fn assert_generic_fn $($rest)+
+        };
+    };
+}
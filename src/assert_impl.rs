@@ -49,6 +49,10 @@
 /// assert_impl_one!(Foo: Snap, Crackle, Pop);
 /// ```
 ///
+/// When the assertion fails, the compiler error refers to
+/// `AtLeastOneTraitImplemented` if none of the traits are implemented, or to
+/// `AtMostOneTraitImplemented` if more than one of them is.
+///
 /// [`assert_impl_any!`]:     macro.assert_impl_any.html
 /// [`assert_impl_all!`]:     macro.assert_impl_all.html
 /// [`assert_not_impl_all!`]: macro.assert_not_impl_all.html
@@ -78,6 +82,42 @@ macro_rules! assert_impl_one {
             // `$x` implements more than one `AmbiguousIfMoreThanOne<Token>` or
             // does not implement any at all.
             let _ = <$x as AmbiguousIfMoreThanOne<_>>::some_item;
+
+            // Fallback used when `$x` does not implement a trait in `$t`.
+            trait DoesNotImpl {
+                const COUNT: usize = 0;
+            }
+
+            impl<T: ?Sized> DoesNotImpl for T {}
+
+            // The number of traits in `$t` implemented by `$x`. Inherent items
+            // take precedence over trait items, so each `Wrapper` only uses
+            // `DoesNotImpl` if its `impl` does not apply.
+            const COUNT: usize = 0 $(+ {
+                #[allow(dead_code)]
+                struct Wrapper<T: ?Sized>($crate::_core::marker::PhantomData<T>);
+
+                #[allow(dead_code)]
+                impl<T: ?Sized + $t> Wrapper<T> {
+                    const COUNT: usize = 1;
+                }
+
+                <Wrapper<$x>>::COUNT
+            })+;
+
+            // Report whether too few or too many traits are implemented via
+            // the names of these traits, which are implemented for `[(); 1]`.
+            trait AtLeastOneTraitImplemented {}
+            trait AtMostOneTraitImplemented {}
+
+            impl AtLeastOneTraitImplemented for [(); 1] {}
+            impl AtMostOneTraitImplemented for [(); 1] {}
+
+            fn at_least_one<T: AtLeastOneTraitImplemented>() {}
+            fn at_most_one<T: AtMostOneTraitImplemented>() {}
+
+            at_least_one::<[(); (COUNT != 0) as usize]>();
+            at_most_one::<[(); (COUNT <= 1) as usize]>();
         };
     };
 }
@@ -105,21 +145,812 @@ macro_rules! assert_impl_one {
 /// assert_impl_all!(*const u8: Send);
 /// ```
 ///
+/// Traits prefixed with `!` are asserted to **not** be implemented, as with
+/// [`assert_not_impl_any!`], and can be mixed with other traits:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// use std::rc::Rc;
+///
+/// assert_impl_all!(Rc<u8>: Clone, !Send, !Sync);
+/// ```
+///
+/// Traits may be given with generic arguments, associated type bindings, and
+/// higher-ranked lifetimes, and lifetime bounds may be listed alongside them.
+/// The same traits can be asserted for several types at once by listing the
+/// types in brackets, where at least two are required since `[T]` is a slice:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// type Parser = fn(&str) -> Option<&str>;
+///
+/// assert_impl_all!(Vec<u8>: AsRef<[u8]>, IntoIterator<Item = u8>, 'static);
+/// assert_impl_all!(Parser: for<'a> Fn(&'a str) -> Option<&'a str>, Copy);
+/// assert_impl_all!([u8, char, (u16, u32)]: Copy, Send, Sync);
+/// ```
+///
+/// Dynamically-sized types such as [`str`] are accepted. Writing `as Sized`
+/// after the type also asserts [`Sized`], which traits with a default generic
+/// parameter of `Self`, such as [`Add`], require unless the parameter is given:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// use std::ops::Add;
+///
+/// assert_impl_all!(str: Send, Sync, AsRef<[u8]>);
+/// assert_impl_all!(i32 as Sized: Add, Add<&'static i32>);
+/// ```
+///
+/// The assertion expands to an item, so it can also be placed within a block,
+/// including the body of a `const fn`.
+///
+/// # `Self`
+///
+/// Items cannot refer to `Self` of an enclosing `impl` or `trait`, so a bare
+/// `Self` is instead checked from within a closure. This can be used in the
+/// bodies of methods and associated functions. Within a default method of a
+/// trait, this checks that the trait's bounds imply the given traits:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// trait Snapshot: Clone {
+///     fn snapshot(&self) -> Self {
+///         assert_impl_all!(Self: Clone);
+///         self.clone()
+///     }
+/// }
+/// ```
+///
+/// # Custom Messages
+///
+/// A message can be provided after a `;` to explain why the traits must be
+/// implemented. If the assertion fails, the message is used as the headline of
+/// the compiler error:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// #[derive(Clone)]
+/// struct Config;
+///
+/// assert_impl_all!(Config: Clone, Default; "`Config` is cloned from defaults");
+/// ```
+///
+/// This makes use of [`#[diagnostic::on_unimplemented]`][on_unimplemented] and
+/// thus requires Rust 1.78 or later. The message is only shown when the type
+/// itself is missing an implementation. If an auto trait such as [`Send`] is
+/// missing because of one of the type's fields, the compiler reports that field
+/// instead. A message can't be combined with a `for<'a>` binding of a generic
+/// associated type whose lifetime requires `Self` to outlive it.
+///
+/// Enabling the `diagnostics` feature flag gives assertions without a message
+/// a default one, stating that the type does not implement a required trait.
+/// Each trait is then checked and reported separately. This only applies to
+/// assertions over a type without `for<...>` generics or higher-ranked bounds
+/// such as `for<'a> Fn(&'a str)`, and not to those over `Self`. This feature
+/// also requires Rust 1.78 or later.
+///
+/// Whenever the message is shown, the error also has the following note, which
+/// tools can look for in the output of the compiler, such as within the
+/// `children` of its [JSON diagnostics][json]:
+///
+/// ```txt
+/// = note: static_assertions: `assert_impl_all!` failed
+/// ```
+///
+/// The text of this note will only change in a new major version of this
+/// crate, unlike the rest of the error, which depends on the compiler. Enabling
+/// the `diagnostics` feature flag ensures it for the assertions without a
+/// message that are given a default one.
+///
+/// # Generics
+///
+/// Implementations that depend on generic parameters can be asserted by
+/// introducing the parameters with a leading `for<...>`. The assertion must hold
+/// for every choice of parameters that satisfies the given bounds, which may
+/// include lifetimes, `const` parameters, and `?Sized`:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// assert_impl_all!(for<T: Send> Vec<T>: Send);
+/// assert_impl_all!(for<T: Clone, U: Clone + ?Sized> (T, Box<U>): Clone);
+/// ```
+///
+/// The following example fails to compile because [`Rc`] is never [`Send`],
+/// even when its contents are:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// use std::rc::Rc;
+///
+/// assert_impl_all!(for<T: Send> Rc<T>: Send);
+/// ```
+///
+/// With several parameters, the generics can instead be written as a `where`
+/// clause followed by `=>` and the assertion:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// assert_impl_all! {
+///     where
+///         T: Send + Clone,
+///         U: Send + ?Sized,
+///     => (T, Box<U>): Send
+/// }
+/// ```
+///
+/// Traits prefixed with `!` can't be asserted with generics, since whether a
+/// trait is implemented can't be decided for all parameters. Because `for<...>`
+/// introduces generics, higher-ranked function pointer types such as
+/// `for<'a> fn(&'a u8)` must be wrapped in parentheses.
+///
+/// # Nightly
+///
+/// Types that can only be named on a nightly compiler, such as `impl Trait`
+/// type aliases and the never type `!`, can be asserted on as well, as can
+/// custom auto traits. On stable Rust, `!` can only be named as the return type
+/// of a function pointer such as `fn() -> !`.
+///
 /// [`assert_not_impl_all!`]: macro.assert_not_impl_all.html
+/// [`assert_not_impl_any!`]: macro.assert_not_impl_any.html
 /// [`Send`]: https://doc.rust-lang.org/std/marker/trait.Send.html
 /// [`Sync`]: https://doc.rust-lang.org/std/marker/trait.Sync.html
+/// [`Rc`]: https://doc.rust-lang.org/std/rc/struct.Rc.html
+/// [`Add`]: https://doc.rust-lang.org/std/ops/trait.Add.html
+/// [`str`]: https://doc.rust-lang.org/std/primitive.str.html
+/// [`Sized`]: https://doc.rust-lang.org/std/marker/trait.Sized.html
 /// [blanket]: https://doc.rust-lang.org/book/ch10-02-traits.html#using-trait-bounds-to-conditionally-implement-methods
-#[macro_export]
+/// [on_unimplemented]: https://doc.rust-lang.org/reference/attributes/diagnostics.html#the-diagnosticon_unimplemented-attribute
+/// [json]: https://doc.rust-lang.org/rustc/json.html
+#[macro_export(local_inner_macros)]
 macro_rules! assert_impl_all {
-    ($type:ty: $($trait:path),+ $(,)?) => {
+    // Labels were required on stable Rust before `const _` and are ignored.
+    ($label:ident; $($rest:tt)*) => {
+        assert_impl_all!($($rest)*);
+    };
+    // `Self` can't be used from within nested items.
+    (Self: $($rest:tt)*) => {
+        _assert_impl_all!(@bounds [] [] [@self] [] [] [] $($rest)*);
+    };
+    (for<$($rest:tt)*) => {
+        _assert_impl_all!(@for [] [] [] [name] $($rest)*);
+    };
+    (where $($rest:tt)+) => {
+        _assert_impl_all!(@where [] $($rest)+);
+    };
+    // At least two types are required so that slices like `[u8]` still work.
+    ([$t0:ty, $($t:ty),+ $(,)?]: $($rest:tt)*) => {
+        _assert_impl_all!(@each [$($rest)*] $t0, $($t),+);
+    };
+    ($type:ty as Sized: $($rest:tt)*) => {
+        _assert_impl_all!(@bounds [] [] [$type] [[$crate::_core::marker::Sized]] [] [] $($rest)*);
+    };
+    ($type:ty: $($rest:tt)*) => {
+        _assert_impl_all!(@bounds [] [] [$type] [] [] [] $($rest)*);
+    };
+}
+
+/// Parses the input of `assert_impl_all!` one token at a time.
+///
+/// Predicates after `where` are collected until `=>` and then parsed as
+/// generics after `for<`.
+///
+/// Generics after `for<` are parsed with the state:
+/// - the generics so far
+/// - the names of the generic parameters so far
+/// - the depth of nested angle brackets, as one `@` per level
+/// - whether a parameter name is expected next
+///
+/// Bounds after `type:` are then parsed with the state:
+/// - the generics and their names
+/// - the type
+/// - the bounds so far, each wrapped in `[]`
+/// - the tokens of the current bound
+/// - the depth of nested angle brackets
+///
+/// Bounds are collected as raw tokens rather than as `path`s so that forms such
+/// as `for<'a> Fn(&'a str)` can be used.
+#[doc(hidden)]
+#[macro_export(local_inner_macros)]
+macro_rules! _assert_impl_all {
+    // Asserts the same traits for each of a list of types.
+    (@each [$($rest:tt)*] $t:ty $(, $ts:ty)*) => {
+        assert_impl_all!($t: $($rest)*);
+        _assert_impl_all!(@each [$($rest)*] $($ts),*);
+    };
+    (@each $rest:tt) => {};
+
+    (@where [$($gen:tt)+] => $($rest:tt)+) => {
+        assert_impl_all!(for<$($gen)+> $($rest)+);
+    };
+    (@where [$($gen:tt)*] $t:tt $($rest:tt)*) => {
+        _assert_impl_all!(@where [$($gen)* $t] $($rest)*);
+    };
+
+    // A parameter's name follows the opening `<` or a top-level `,`.
+    (@for [$($gen:tt)*] [$($name:tt)*] [] [name] $lt:lifetime $($rest:tt)*) => {
+        _assert_impl_all!(@for [$($gen)* $lt] [$($name)* $lt,] [] [] $($rest)*);
+    };
+    // Braces ensure const parameters are not mistaken for types of the same
+    // name when passed as generic arguments.
+    (@for [$($gen:tt)*] [$($name:tt)*] [] [name] const $n:ident $($rest:tt)*) => {
+        _assert_impl_all!(@for [$($gen)* const $n] [$($name)* { $n },] [] [] $($rest)*);
+    };
+    (@for [$($gen:tt)*] [$($name:tt)*] [] [name] $n:ident $($rest:tt)*) => {
+        _assert_impl_all!(@for [$($gen)* $n] [$($name)* $n,] [] [] $($rest)*);
+    };
+    (@for [$($gen:tt)*] [$($name:tt)*] [] [] , $($rest:tt)*) => {
+        _assert_impl_all!(@for [$($gen)* ,] [$($name)*] [] [name] $($rest)*);
+    };
+
+    // The closing `>` of the generics, which may be glued to that of a bound.
+    (@for [$($gen:tt)*] [$($name:tt)*] [] [$($n:tt)*] > $($rest:tt)*) => {
+        _assert_impl_all!(@type [$($gen)*] [$($name)*] $($rest)*);
+    };
+    (@for [$($gen:tt)*] [$($name:tt)*] [@] [] >> $($rest:tt)*) => {
+        _assert_impl_all!(@type [$($gen)* >] [$($name)*] $($rest)*);
+    };
+
+    // Angle brackets nested within bounds of the generics.
+    (@for [$($gen:tt)*] [$($name:tt)*] [@ @ $($d:tt)*] [] >> $($rest:tt)*) => {
+        _assert_impl_all!(@for [$($gen)* >>] [$($name)*] [$($d)*] [] $($rest)*);
+    };
+    (@for [$($gen:tt)*] [$($name:tt)*] [@ $($d:tt)*] [] > $($rest:tt)*) => {
+        _assert_impl_all!(@for [$($gen)* >] [$($name)*] [$($d)*] [] $($rest)*);
+    };
+    (@for [$($gen:tt)*] [$($name:tt)*] [$($d:tt)*] [] << $($rest:tt)*) => {
+        _assert_impl_all!(@for [$($gen)* <<] [$($name)*] [@ @ $($d)*] [] $($rest)*);
+    };
+    (@for [$($gen:tt)*] [$($name:tt)*] [$($d:tt)*] [] < $($rest:tt)*) => {
+        _assert_impl_all!(@for [$($gen)* <] [$($name)*] [@ $($d)*] [] $($rest)*);
+    };
+
+    // Any other token of the generics.
+    (@for [$($gen:tt)*] [$($name:tt)*] [$($d:tt)*] [] $t:tt $($rest:tt)*) => {
+        _assert_impl_all!(@for [$($gen)* $t] [$($name)*] [$($d)*] [] $($rest)*);
+    };
+
+    (@type [$($gen:tt)*] [$($name:tt)*] $type:ty: $($rest:tt)*) => {
+        _assert_impl_all!(@bounds [$($gen)*] [$($name)*] [$type] [] [] [] $($rest)*);
+    };
+
+    // A top-level `,` ends the current bound.
+    (@bounds $gen:tt $name:tt $type:tt [$($done:tt)*] [$($cur:tt)+] [] , $($rest:tt)*) => {
+        _assert_impl_all!(@bounds $gen $name $type [$($done)* [$($cur)+]] [] [] $($rest)*);
+    };
+
+    // Repeated `,`s, such as from other macros, are ignored.
+    (@bounds $gen:tt $name:tt $type:tt $done:tt [] [] , $($rest:tt)*) => {
+        _assert_impl_all!(@bounds $gen $name $type $done [] [] $($rest)*);
+    };
+
+    (@bounds $gen:tt $name:tt $type:tt [] [] [] $(; $msg:literal $(,)?)?) => {
+        $crate::_core::compile_error!(
+            "`assert_impl_all!` requires at least one trait after the `:`"
+        );
+    };
+
+    // The end of the bounds, optionally followed by a message.
+    (@bounds $gen:tt $name:tt $type:tt [$($done:tt)*] [$($cur:tt)+] []) => {
+        _assert_impl_all!(@split $gen $name $type [] [] [$($done)* [$($cur)+]] []);
+    };
+    (@bounds $gen:tt $name:tt $type:tt [$($done:tt)+] [] []) => {
+        _assert_impl_all!(@split $gen $name $type [] [] [$($done)+] []);
+    };
+    (@bounds $gen:tt $name:tt $type:tt [$($done:tt)*] [$($cur:tt)+] [] ; $msg:literal $(,)?) => {
+        _assert_impl_all!(@split $gen $name $type [] [] [$($done)* [$($cur)+]] [$msg]);
+    };
+    (@bounds $gen:tt $name:tt $type:tt [$($done:tt)+] [] [] ; $msg:literal $(,)?) => {
+        _assert_impl_all!(@split $gen $name $type [] [] [$($done)+] [$msg]);
+    };
+
+    // Angle brackets nested within a bound.
+    (@bounds $gen:tt $name:tt $type:tt $done:tt [$($cur:tt)*] [@ @ $($d:tt)*] >> $($rest:tt)*) => {
+        _assert_impl_all!(@bounds $gen $name $type $done [$($cur)* >>] [$($d)*] $($rest)*);
+    };
+    (@bounds $gen:tt $name:tt $type:tt $done:tt [$($cur:tt)*] [@ $($d:tt)*] > $($rest:tt)*) => {
+        _assert_impl_all!(@bounds $gen $name $type $done [$($cur)* >] [$($d)*] $($rest)*);
+    };
+    (@bounds $gen:tt $name:tt $type:tt $done:tt [$($cur:tt)*] [$($d:tt)*] << $($rest:tt)*) => {
+        _assert_impl_all!(@bounds $gen $name $type $done [$($cur)* <<] [@ @ $($d)*] $($rest)*);
+    };
+    (@bounds $gen:tt $name:tt $type:tt $done:tt [$($cur:tt)*] [$($d:tt)*] < $($rest:tt)*) => {
+        _assert_impl_all!(@bounds $gen $name $type $done [$($cur)* <] [@ $($d)*] $($rest)*);
+    };
+
+    // Segments of paths such as `::core::marker::Send` are taken several at a
+    // time, since taking one token at a time quickly reaches the recursion
+    // limit.
+    (@bounds $gen:tt $name:tt $type:tt $done:tt [$($cur:tt)*] $d:tt :: $a:ident :: $b:ident :: $c:ident $($rest:tt)*) => {
+        _assert_impl_all!(@bounds $gen $name $type $done [$($cur)* :: $a :: $b :: $c] $d $($rest)*);
+    };
+    (@bounds $gen:tt $name:tt $type:tt $done:tt [$($cur:tt)*] $d:tt $a:ident :: $b:ident :: $c:ident $($rest:tt)*) => {
+        _assert_impl_all!(@bounds $gen $name $type $done [$($cur)* $a :: $b :: $c] $d $($rest)*);
+    };
+    (@bounds $gen:tt $name:tt $type:tt $done:tt [$($cur:tt)*] $d:tt $a:ident :: $b:ident $($rest:tt)*) => {
+        _assert_impl_all!(@bounds $gen $name $type $done [$($cur)* $a :: $b] $d $($rest)*);
+    };
+    (@bounds $gen:tt $name:tt $type:tt $done:tt [$($cur:tt)*] $d:tt :: $a:ident $($rest:tt)*) => {
+        _assert_impl_all!(@bounds $gen $name $type $done [$($cur)* :: $a] $d $($rest)*);
+    };
+
+    // Any other token of a bound.
+    (@bounds $gen:tt $name:tt $type:tt $done:tt [$($cur:tt)*] $d:tt $t:tt $($rest:tt)*) => {
+        _assert_impl_all!(@bounds $gen $name $type $done [$($cur)* $t] $d $($rest)*);
+    };
+
+    // Splits negative bounds such as `!Send` from the others, since they are
+    // asserted separately.
+    (@split $gen:tt $name:tt $type:tt [$($pos:tt)*] [$($neg:tt)*] [[! $($b:tt)+] $($rest:tt)*] $msg:tt) => {
+        _assert_impl_all!(@split $gen $name $type [$($pos)*] [$($neg)* [$($b)+]] [$($rest)*] $msg);
+    };
+    (@split $gen:tt $name:tt $type:tt [$($pos:tt)*] [$($neg:tt)*] [[$($b:tt)+] $($rest:tt)*] $msg:tt) => {
+        _assert_impl_all!(@split $gen $name $type [$($pos)* [$($b)+]] [$($neg)*] [$($rest)*] $msg);
+    };
+    (@split $gen:tt $name:tt $type:tt [$($pos:tt)+] [] [] $msg:tt) => {
+        _assert_impl_all!(@emit $gen $name $type [$($pos)+] $msg);
+    };
+    // Negative bounds of `Self`, which are asserted as by
+    // `assert_not_impl_any!` from within a closure.
+    (@split [] [] [@self] [$($pos:tt)*] [$([$($neg:tt)+])+] [] $msg:tt) => {
+        _assert_impl_all!(@split [] [] [@self] [$($pos)*] [] [] $msg);
+        let _ = || {
+            trait AmbiguousIfImpl<A> {
+                fn some_item() {}
+            }
+
+            impl<T: ?Sized> AmbiguousIfImpl<()> for T {}
+
+            $({
+                #[allow(dead_code)]
+                struct Invalid;
+
+                impl<T: ?Sized + $($neg)+> AmbiguousIfImpl<Invalid> for T {}
+            })+
+
+            let _ = <Self as AmbiguousIfImpl<_>>::some_item;
+        };
+    };
+    (@split [] [] [@val $x:tt] $pos:tt [$($neg:tt)+] [] $msg:tt) => {
+        $crate::_core::compile_error!(
+            "`assert_impl_val!` does not support negative bounds such as `!Send`"
+        );
+    };
+    (@split [] [] [$type:ty] [$($pos:tt)*] [$([$($neg:tt)+])+] [] $msg:tt) => {
+        _assert_impl_all!(@split [] [] [$type] [$($pos)*] [] [] $msg);
+        $(assert_not_impl_any!($type: $($neg)+);)+
+    };
+    // Whether a trait is implemented can't be decided for all parameters.
+    (@split $gen:tt $name:tt $type:tt $pos:tt [$($neg:tt)+] [] $msg:tt) => {
+        $crate::_core::compile_error!(
+            "`assert_impl_all!` does not support negative bounds such as `!Send` with generics"
+        );
+    };
+    (@split $gen:tt $name:tt $type:tt [] [] [] $msg:tt) => {};
+
+    // Values from `assert_impl_val!`, whose type is inferred.
+    (@emit [] [] [@val $x:tt] [$([$($bound:tt)+])+] []) => {
+        let _ = || {
+            // Only callable when the type of `$x` implements all traits in
+            // `$($bound)+`.
+            fn assert_impl_val<T: ?Sized $(+ $($bound)+)+>(_: &T) {}
+            assert_impl_val(&$x);
+        };
+    };
+
+    // `Self`, which is only checked from within a closure.
+    (@emit [] [] [@self] [$([$($bound:tt)+])+] []) => {
+        let _ = || {
+            fn assert_impl_all<T: ?Sized $(+ $($bound)+)+>() {}
+            assert_impl_all::<Self>();
+        };
+    };
+    (@emit [] [] [@self] [$([$($bound:tt)+])+] [$msg:literal]) => {
+        let _ = || {
+            #[diagnostic::on_unimplemented(
+                message = $msg,
+                note = "static_assertions: `assert_impl_all!` failed",
+            )]
+            trait AssertImplAll {}
+
+            impl<T: ?Sized $(+ $($bound)+)+> AssertImplAll for T {}
+
+            fn assert_impl_all<T: ?Sized + AssertImplAll>() {}
+            assert_impl_all::<Self>();
+        };
+    };
+
+    (@emit [] [] [$type:ty] [$([$($bound:tt)+])+] []) => {
+        _assert_impl_all_plain!([$type] $([$($bound)+])+);
+    };
+    (@plain [$type:ty] $([$($bound:tt)+])+) => {
+        const _: fn() = || {
+            // Only callable when `$type` implements all traits in `$($bound)+`.
+            // `?Sized` only removes the implicit bound, so an explicit `Sized`
+            // in `$($bound)+` is still required.
+            fn assert_impl_all<T: ?Sized $(+ $($bound)+)+>() {}
+            assert_impl_all::<$type>();
+        };
+    };
+    (@emit [] [] [$type:ty] [$([$($bound:tt)+])+] [$msg:literal]) => {
         const _: fn() = || {
-            // Only callable when `$type` implements all traits in `$($trait)+`.
-            fn assert_impl_all<T: ?Sized $(+ $trait)+>() {}
+            // Implemented for all types that implement all traits in
+            // `$($bound)+`. Failing to satisfy this bound reports `$msg`.
+            #[diagnostic::on_unimplemented(
+                message = $msg,
+                note = "static_assertions: `assert_impl_all!` failed",
+            )]
+            trait AssertImplAll {}
+
+            impl<T: ?Sized $(+ $($bound)+)+> AssertImplAll for T {}
+
+            fn assert_impl_all<T: ?Sized + AssertImplAll>() {}
+            assert_impl_all::<$type>();
+        };
+    };
+    (@emit [$($gen:tt)*] [$($name:tt)*] [$type:ty] [$([$($bound:tt)+])+] []) => {
+        const _: () = {
+            #[allow(dead_code)]
+            fn assert_impl_all<$($gen)*>() {
+                // Items can't refer to the outer generic parameters, so they
+                // are redeclared here. Calling this with the outer parameters
+                // requires proving the `where` clause for all of them.
+                #[allow(unknown_lints, clippy::multiple_bound_locations)]
+                fn assert_impl_all<$($gen)*>() where $($type: $($bound)+,)+ {}
+                assert_impl_all::<$($name)*>();
+            }
+        };
+    };
+    (@emit [$($gen:tt)*] [$($name:tt)*] [$type:ty] [$([$($bound:tt)+])+] [$msg:literal]) => {
+        const _: () = {
+            // Takes the generic parameters so that the impl can refer to them.
+            #[diagnostic::on_unimplemented(
+                message = $msg,
+                note = "static_assertions: `assert_impl_all!` failed",
+            )]
+            trait AssertImplAll<$($gen)*> {}
+
+            #[allow(unknown_lints, clippy::multiple_bound_locations)]
+            impl<$($gen)*> AssertImplAll<$($name)*> for $type where $($type: $($bound)+,)+ {}
+
+            #[allow(dead_code)]
+            fn assert_impl_all<$($gen)*>() {
+                #[allow(unknown_lints, clippy::multiple_bound_locations)]
+                fn assert_impl_all<$($gen)*>() where $type: AssertImplAll<$($name)*> {}
+                assert_impl_all::<$($name)*>();
+            }
+        };
+    };
+}
+
+/// Emits `assert_impl_all!` without a custom message.
+///
+/// With the `diagnostics` feature, each bound is asserted separately with a
+/// default message. This is defined here rather than via `#[cfg]` within the
+/// expansion, which would check the features of the calling crate instead.
+///
+/// Enabling the feature must not change which assertions hold, so bounds that
+/// can't be asserted separately are handled first:
+/// - higher-ranked bounds such as `for<'a> Collection<Iter<'a> = ..>` can't be
+///   required by a trait implementation, so the whole assertion is emitted as
+///   it is without the feature
+/// - with `Sized` among the bounds, each trait is asserted for sized types,
+///   since traits such as `Add<Rhs = Self>` are only well-formed for those
+#[doc(hidden)]
+#[cfg(feature = "diagnostics")]
+#[macro_export(local_inner_macros)]
+macro_rules! _assert_impl_all_plain {
+    ([$type:ty] $($bounds:tt)+) => {
+        _assert_impl_all_plain!(@scan [$type] [?Sized +] [$($bounds)+] $($bounds)+);
+    };
+
+    (@scan [$type:ty] $relax:tt [$($all:tt)+] [for $($b:tt)+] $($rest:tt)*) => {
+        _assert_impl_all!(@plain [$type] $($all)+);
+    };
+    (@scan $type:tt $relax:tt $all:tt [Sized] $($rest:tt)*) => {
+        _assert_impl_all_plain!(@scan $type [] $all $($rest)*);
+    };
+    (@scan $type:tt $relax:tt $all:tt [:: $a:ident :: $b:ident :: Sized] $($rest:tt)*) => {
+        _assert_impl_all_plain!(@scan $type [] $all $($rest)*);
+    };
+    (@scan $type:tt $relax:tt $all:tt [$a:ident :: $b:ident :: Sized] $($rest:tt)*) => {
+        _assert_impl_all_plain!(@scan $type [] $all $($rest)*);
+    };
+    (@scan $type:tt $relax:tt $all:tt [$a:ident :: $b:ident :: $c:ident :: Sized] $($rest:tt)*) => {
+        _assert_impl_all_plain!(@scan $type [] $all $($rest)*);
+    };
+    (@scan $type:tt $relax:tt $all:tt $bound:tt $($rest:tt)*) => {
+        _assert_impl_all_plain!(@scan $type $relax $all $($rest)*);
+    };
+    (@scan $type:tt $relax:tt [$($bound:tt)+]) => {
+        $(_assert_impl_all_plain!(@check $type $relax $bound);)+
+    };
+
+    (@check [$type:ty] [$($relax:tt)*] [$($bound:tt)+]) => {
+        const _: fn() = || {
+            #[diagnostic::on_unimplemented(
+                message = "`{Self}` does not implement a trait required by `assert_impl_all!`",
+                note = "static_assertions: `assert_impl_all!` failed",
+            )]
+            trait AssertImplAll {}
+
+            impl<T: $($relax)* $($bound)+> AssertImplAll for T {}
+
+            fn assert_impl_all<T: ?Sized + AssertImplAll>() {}
             assert_impl_all::<$type>();
         };
     };
 }
 
+#[doc(hidden)]
+#[cfg(not(feature = "diagnostics"))]
+#[macro_export(local_inner_macros)]
+macro_rules! _assert_impl_all_plain {
+    ($($t:tt)+) => {
+        _assert_impl_all!(@plain $($t)+);
+    };
+}
+
+/// Asserts that the type implements _all_ of the given traits, but only when the
+/// given configuration is set.
+///
+/// This is [`assert_impl_all!`] behind a [`#[cfg]`][cfg] attribute. It is
+/// useful for traits that only exist on some targets, such as extension traits
+/// in [`std::os`]. When the configuration is not set, the assertion is removed
+/// before any of its paths are resolved, so they may refer to items that do not
+/// exist.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// use std::fs::File;
+///
+/// assert_impl_all_cfg!(unix, File: std::os::unix::fs::FileExt, Send);
+/// assert_impl_all_cfg!(windows, File: std::os::windows::fs::FileExt, Send);
+///
+/// assert_impl_all_cfg!(
+///     all(unix, not(target_os = "fuchsia")),
+///     File: std::os::unix::io::AsRawFd; "raw file descriptors are needed for polling"
+/// );
+/// ```
+///
+/// The following example fails to compile on every target, because the
+/// configuration is always set and [`String`] is not a file:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// assert_impl_all_cfg!(any(unix, not(unix)), String: std::io::Read);
+/// ```
+///
+/// [`assert_impl_all!`]: macro.assert_impl_all.html
+/// [cfg]: https://doc.rust-lang.org/reference/conditional-compilation.html#the-cfg-attribute
+/// [`std::os`]: https://doc.rust-lang.org/std/os/index.html
+/// [`String`]: https://doc.rust-lang.org/std/string/struct.String.html
+#[macro_export(local_inner_macros)]
+macro_rules! assert_impl_all_cfg {
+    ($cfg:meta, $($rest:tt)+) => {
+        #[cfg($cfg)]
+        const _: () = {
+            assert_impl_all!($($rest)+);
+        };
+    };
+}
+
+/// Asserts that the type implements _all_ of the given traits, but only when
+/// compiling tests.
+///
+/// This is shorthand for [`assert_impl_all_cfg!`] with `test` as the
+/// configuration. The assertion is checked by `cargo test` and adds nothing to
+/// other builds, trading a check on every build for one whenever tests are
+/// built. Since doctests and dependents are not compiled with `cfg(test)`, it
+/// is also not checked there.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// struct Config(String);
+///
+/// test_assert_impl_all!(Config: Send, Sync);
+/// test_assert_impl_all!(u32: Copy; "only checked by `cargo test`");
+/// ```
+///
+/// The following example compiles outside of tests even though [`String`] is
+/// not [`Copy`], and fails to compile under `cargo test`:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// test_assert_impl_all!(String: Copy);
+/// ```
+///
+/// [`assert_impl_all_cfg!`]: macro.assert_impl_all_cfg.html
+/// [`String`]: https://doc.rust-lang.org/std/string/struct.String.html
+/// [`Copy`]: https://doc.rust-lang.org/std/marker/trait.Copy.html
+#[macro_export(local_inner_macros)]
+macro_rules! test_assert_impl_all {
+    ($($rest:tt)+) => {
+        assert_impl_all_cfg!(test, $($rest)+);
+    };
+}
+
+/// Asserts that shared and mutable references to the type implement _all_ of
+/// the given traits.
+///
+/// This checks that both `&'a T` and `&'a mut T` implement the traits for
+/// _every_ lifetime `'a`. It is shorthand for:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// # type T = u8;
+/// assert_impl_all!(for<'a> &'a T: Send, Sync);
+/// assert_impl_all!(for<'a> &'a mut T: Send, Sync);
+/// ```
+///
+/// Types containing lifetimes must name them, such as with `Foo<'static>`.
+///
+/// See [`assert_impl_all!`] for asserting traits on the type itself.
+///
+/// # Examples
+///
+/// A reference can be sent to another thread when the type is [`Sync`], and a
+/// mutable reference can be sent when the type is [`Send`]:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// assert_impl_ref!(u8: Send, Sync);
+/// assert_impl_ref!(str: Send, Sync, AsRef<[u8]>);
+/// ```
+///
+/// The following example fails to compile because [`Cell`] is not [`Sync`], so
+/// `&Cell<u8>` is not [`Send`]:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// use std::cell::Cell;
+///
+/// assert_impl_ref!(Cell<u8>: Send);
+/// ```
+///
+/// Mutable references are checked too. The following example fails to compile
+/// because `&mut u8` does not implement [`Copy`]:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// assert_impl_ref!(u8: Copy);
+/// ```
+///
+/// [`assert_impl_all!`]: macro.assert_impl_all.html
+/// [`Cell`]: https://doc.rust-lang.org/std/cell/struct.Cell.html
+/// [`Copy`]: https://doc.rust-lang.org/std/marker/trait.Copy.html
+/// [`Send`]: https://doc.rust-lang.org/std/marker/trait.Send.html
+/// [`Sync`]: https://doc.rust-lang.org/std/marker/trait.Sync.html
+#[macro_export(local_inner_macros)]
+macro_rules! assert_impl_ref {
+    ($type:ty: $($rest:tt)+) => {
+        assert_impl_all!(for<'a> &'a $type: $($rest)+);
+        assert_impl_all!(for<'a> &'a mut $type: $($rest)+);
+    };
+}
+
+/// Asserts that the type of a value implements _all_ of the given traits.
+///
+/// This is the value-level analog of [`assert_impl_all!`] and is useful for
+/// types that can't be named, such as those of closures. The value is only
+/// referenced from within a closure that is never called, so it is never
+/// evaluated for its side effects.
+///
+/// The value must be a single token tree, such as a variable name or an
+/// expression wrapped in parentheses or braces. Like [`assert_eq_size_val!`],
+/// this can only be used where statements are allowed.
+///
+/// # Examples
+///
+/// Closures can be asserted to implement the [`Fn`] family of traits:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions;
+/// # fn main() {
+/// let mut count = 0;
+/// let mut filter = |byte: u8| {
+///     count += 1;
+///     byte.is_ascii()
+/// };
+///
+/// assert_impl_val!(filter: FnMut(u8) -> bool);
+/// assert_impl_val!((|byte: u8| byte == 0): Fn(u8) -> bool, Copy, Send);
+/// # filter(0);
+/// # }
+/// ```
+///
+/// The following example fails to compile because the closure takes a `u16`
+/// rather than a `u8`:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions;
+/// # fn main() {
+/// let filter = |value: u16| value > 0;
+///
+/// assert_impl_val!(filter: FnMut(u8) -> bool);
+/// # }
+/// ```
+///
+/// Likewise, this fails to compile because the closure mutates its captured
+/// state, so it only implements [`FnMut`]:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions;
+/// # fn main() {
+/// let mut count = 0;
+/// let mut counter = || count += 1;
+///
+/// assert_impl_val!(counter: Fn());
+/// # counter();
+/// # }
+/// ```
+///
+/// [`assert_impl_all!`]: macro.assert_impl_all.html
+/// [`assert_eq_size_val!`]: macro.assert_eq_size_val.html
+/// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+/// [`FnMut`]: https://doc.rust-lang.org/std/ops/trait.FnMut.html
+#[macro_export(local_inner_macros)]
+macro_rules! assert_impl_val {
+    ($x:tt: $($rest:tt)+) => {
+        _assert_impl_all!(@bounds [] [] [@val $x] [] [] [] $($rest)+);
+    };
+}
+
+/// Asserts that an iterator yields items of the given type.
+///
+/// This is useful for iterators built from chains of adapters, whose types are
+/// tedious or impossible to name. The iterator is only referenced from within
+/// a closure that is never called, so it is neither evaluated nor consumed.
+///
+/// Like [`assert_eq_size_val!`], this can only be used where statements are
+/// allowed. For iterator types that can be named, use
+/// `assert_impl_all!(T: Iterator<Item = U>)` instead.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions;
+/// # fn main() {
+/// let words = ["a", "bc", "def"];
+/// let lengths = words.iter().map(|word| word.len() as u32);
+///
+/// assert_iter_item!(lengths, u32);
+/// assert_iter_item!(words.iter().zip(lengths), (&&str, u32));
+/// assert_iter_item!((0u8..5).filter(|x| x % 2 == 0), u8);
+/// # }
+/// ```
+///
+/// The item type must match exactly. The following example fails to compile
+/// because the iterator yields references:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions;
+/// # fn main() {
+/// let bytes = [1u8, 2, 3];
+///
+/// assert_iter_item!(bytes.iter(), u8);
+/// # }
+/// ```
+///
+/// [`assert_eq_size_val!`]: macro.assert_eq_size_val.html
+#[macro_export]
+macro_rules! assert_iter_item {
+    ($x:expr, $item:ty $(,)?) => {
+        let _ = || {
+            // Only callable when `I` yields items of type `T`.
+            fn assert_iter_item<I, T>(_: &I)
+            where
+                I: ?Sized + $crate::_core::iter::Iterator<Item = T>,
+            {
+            }
+            assert_iter_item::<_, $item>(&$x);
+        };
+    };
+}
+
 /// Asserts that the type implements _any_ of the given traits.
 ///
 /// See [`assert_not_impl_any!`] for achieving the opposite effect.
@@ -237,6 +1068,14 @@ macro_rules! assert_impl_any {
 /// assert_not_impl_all!(u32: Into<u64>);
 /// ```
 ///
+/// Likewise, this fails to compile because `u32` implements both [`Copy`] and
+/// [`Send`]:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// assert_not_impl_all!(u32: Copy, Send);
+/// ```
+///
 /// The following compiles because [`Cell`] is not both [`Sync`] _and_ [`Send`]:
 ///
 /// ```
@@ -250,17 +1089,22 @@ macro_rules! assert_impl_any {
 ///
 /// ```compile_fail
 /// # #[macro_use] extern crate static_assertions; fn main() {}
-/// # std::cell::Cell;
+/// # use std::cell::Cell;
 /// assert_not_impl_all!(Cell<u32>: Send);
 /// ```
 ///
+/// [`Copy`]: https://doc.rust-lang.org/std/marker/trait.Copy.html
 /// [`Send`]: https://doc.rust-lang.org/std/marker/trait.Send.html
 /// [`Sync`]: https://doc.rust-lang.org/std/marker/trait.Sync.html
 /// [`assert_not_impl_any!`]: macro.assert_not_impl_any.html
 /// [`Cell`]: https://doc.rust-lang.org/std/cell/struct.Cell.html
 /// [blanket]: https://doc.rust-lang.org/book/ch10-02-traits.html#using-trait-bounds-to-conditionally-implement-methods
-#[macro_export]
+#[macro_export(local_inner_macros)]
 macro_rules! assert_not_impl_all {
+    // Labels were required on stable Rust before `const _` and are ignored.
+    ($label:ident; $($rest:tt)*) => {
+        assert_not_impl_all!($($rest)*);
+    };
     ($x:ty: $($t:path),+ $(,)?) => {
         const _: fn() = || {
             // Generic trait with a blanket impl over `()` for all types.
@@ -313,6 +1157,16 @@ macro_rules! assert_not_impl_all {
 /// assert_not_impl_any!(&'static mut u8: Copy);
 /// ```
 ///
+/// A wrapper around a raw pointer can be proven to be neither [`Send`] nor
+/// [`Sync`]:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// struct MyPtr(*const u8);
+///
+/// assert_not_impl_any!(MyPtr: Send, Sync);
+/// ```
+///
 /// The following example fails to compile since `u32` can be converted into
 /// `u64` even though it can not be converted into a `u16`:
 ///
@@ -321,12 +1175,26 @@ macro_rules! assert_not_impl_all {
 /// assert_not_impl_any!(u32: Into<u64>, Into<u16>);
 /// ```
 ///
+/// Implementing just one of the traits is enough to cause a failure. Here `u8`
+/// implements both [`Copy`] and [`Clone`]:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// assert_not_impl_any!(u8: Copy, Clone);
+/// ```
+///
+/// [`Copy`]: https://doc.rust-lang.org/std/marker/trait.Copy.html
+/// [`Clone`]: https://doc.rust-lang.org/std/clone/trait.Clone.html
 /// [`Send`]: https://doc.rust-lang.org/std/marker/trait.Send.html
 /// [`Sync`]: https://doc.rust-lang.org/std/marker/trait.Sync.html
 /// [`assert_not_impl_all!`]: macro.assert_not_impl_all.html
 /// [blanket]: https://doc.rust-lang.org/book/ch10-02-traits.html#using-trait-bounds-to-conditionally-implement-methods
-#[macro_export]
+#[macro_export(local_inner_macros)]
 macro_rules! assert_not_impl_any {
+    // Labels were required on stable Rust before `const _` and are ignored.
+    ($label:ident; $($rest:tt)*) => {
+        assert_not_impl_any!($($rest)*);
+    };
     ($x:ty: $($t:path),+ $(,)?) => {
         const _: fn() = || {
             // Generic trait with a blanket impl over `()` for all types.
@@ -0,0 +1,224 @@
+/// Asserts that the fields of a type are at the given byte offsets.
+///
+/// This is especially useful for [`#[repr(C)]`][repr] types used with
+/// [FFI], whose layout must match what is expected on the other side. Offsets
+/// are computed with [`offset_of!`], which requires Rust 1.77 or later.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// #[repr(C)]
+/// struct Header {
+///     magic: u32,
+///     version: u16,
+///     flags: u16,
+/// }
+///
+/// assert_repr_c_layout!(Header { magic: 0, version: 4, flags: 6 });
+/// ```
+///
+/// Packed types and tuple structs work too:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// #[repr(C, packed)]
+/// struct Packed(u8, u32);
+///
+/// assert_repr_c_layout!(Packed { 0: 0, 1: 1 });
+/// ```
+///
+/// Only the listed offsets are checked. Trailing padding can be checked by also
+/// asserting the size of the type, such as with [`assert_eq_size!`].
+///
+/// The following example fails to compile because `version` is aligned to 4
+/// bytes, leaving padding after `flag`:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// #[repr(C)]
+/// struct Header {
+///     flag: u8,
+///     version: u32,
+/// }
+///
+/// assert_repr_c_layout!(Header { flag: 0, version: 1 });
+/// ```
+///
+/// [repr]: https://doc.rust-lang.org/nomicon/other-reprs.html#reprc
+/// [FFI]: https://en.wikipedia.org/wiki/Foreign_function_interface
+/// [`offset_of!`]: https://doc.rust-lang.org/core/mem/macro.offset_of.html
+/// [`assert_eq_size!`]: macro.assert_eq_size.html
+#[macro_export(local_inner_macros)]
+macro_rules! assert_repr_c_layout {
+    ($t:ty { $($f:tt: $offset:expr),+ $(,)? }) => {
+        $(_const_assert!($crate::_core::mem::offset_of!($t, $f) == $offset);)+
+    };
+}
+
+/// Asserts that a struct has no padding bytes.
+///
+/// This is useful for types that are hashed, compared, or serialized as raw
+/// bytes, where padding would be uninitialized. Every field must be listed with
+/// its type, since fields can't be enumerated otherwise. The size of the struct
+/// is then asserted to be the sum of the sizes of its fields.
+///
+/// The list is checked to be exhaustive and each type must be exactly that of
+/// its field, without any coercion, so it can't silently go out of date. As
+/// such, the fields must be visible where this is used.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// #[repr(C)]
+/// struct Header {
+///     magic: u32,
+///     version: u16,
+///     flags: u16,
+/// }
+///
+/// assert_no_padding!(Header { magic: u32, version: u16, flags: u16 });
+/// ```
+///
+/// Tuple structs can list their fields by index, and a message can be provided
+/// to explain the assertion. It is reported if the assertion fails, which
+/// requires Rust 1.57 or later:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// #[repr(C)]
+/// struct Rgba(u8, u8, u8, u8);
+///
+/// assert_no_padding!(Rgba { 0: u8, 1: u8, 2: u8, 3: u8 }, "pixels are hashed as bytes");
+/// ```
+///
+/// The following example fails to compile because `version` is aligned to 4
+/// bytes, leaving padding after `flag`:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// #[repr(C)]
+/// struct Header {
+///     flag: u8,
+///     version: u32,
+/// }
+///
+/// assert_no_padding!(Header { flag: u8, version: u32 });
+/// ```
+///
+/// Likewise, this fails to compile because `flags` is not listed:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// #[repr(C)]
+/// struct Header {
+///     magic: u32,
+///     version: u16,
+///     flags: u16,
+/// }
+///
+/// assert_no_padding!(Header { magic: u32, version: u16 });
+/// ```
+#[macro_export(local_inner_macros)]
+macro_rules! assert_no_padding {
+    ($t:ty { $($f:tt: $ft:ty),+ $(,)? } $(, $msg:literal)? $(,)?) => {
+        const _: () = {
+            type Struct = $t;
+
+            // Field types must match exactly. A value of the listed type could
+            // be coerced to the field's type, but an inference variable can't.
+            trait Is<T: ?Sized> {}
+            impl<T: ?Sized> Is<T> for T {}
+
+            #[allow(dead_code)]
+            fn field<T, U: Is<T>>() -> U {
+                loop {}
+            }
+
+            // Fails if a field is missing from the list or has another type.
+            // Building the struct rather than borrowing its fields also works
+            // for `#[repr(packed)]` structs.
+            #[allow(dead_code)]
+            fn fields() -> Struct {
+                Struct { $($f: field::<$ft, _>()),+ }
+            }
+
+            _assert_no_padding!(
+                $crate::_core::mem::size_of::<Struct>()
+                    == 0 $(+ $crate::_core::mem::size_of::<$ft>())+
+                $(, $msg)?
+            );
+        };
+    };
+}
+
+#[doc(hidden)]
+#[macro_export(local_inner_macros)]
+macro_rules! _assert_no_padding {
+    ($x:expr) => {
+        _const_assert!($x);
+    };
+    ($x:expr, $msg:literal) => {
+        const _: () = $crate::_core::assert!($x, "{}", $msg);
+    };
+}
+
+/// Asserts that types have the same size _and_ alignment.
+///
+/// This combines [`assert_eq_size!`] and [`assert_eq_align!`], which are often
+/// needed together before transmuting between types or reinterpreting
+/// pointers. Size is checked before alignment, and the error names the types
+/// and which of the two differs. This requires Rust 1.57 or later.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// #[repr(transparent)]
+/// struct Meters(f64);
+///
+/// assert_layout_eq!(u32, i32, char);
+/// assert_layout_eq!(Meters, f64, u64);
+/// ```
+///
+/// The following example fails to compile because `[u8; 4]` is only aligned to
+/// 1 byte, despite having the same size as `u32`:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// assert_layout_eq!(u32, [u8; 4]);
+/// ```
+///
+/// This reports:
+///
+/// ```txt
+/// error[E0080]: evaluation panicked: `u32` and `[u8; 4]` differ in alignment
+/// ```
+///
+/// [`assert_eq_size!`]: macro.assert_eq_size.html
+/// [`assert_eq_align!`]: macro.assert_eq_align.html
+#[macro_export]
+macro_rules! assert_layout_eq {
+    ($x:ty, $($xs:ty),+ $(,)?) => {
+        $(const _: () = {
+            use $crate::_core::mem::{align_of, size_of};
+            $crate::_core::assert!(
+                size_of::<$x>() == size_of::<$xs>(),
+                "{}",
+                $crate::_core::concat!(
+                    "`", $crate::_core::stringify!($x), "` and `",
+                    $crate::_core::stringify!($xs), "` differ in size",
+                ),
+            );
+            $crate::_core::assert!(
+                align_of::<$x>() == align_of::<$xs>(),
+                "{}",
+                $crate::_core::concat!(
+                    "`", $crate::_core::stringify!($x), "` and `",
+                    $crate::_core::stringify!($xs), "` differ in alignment",
+                ),
+            );
+        };)+
+    };
+}
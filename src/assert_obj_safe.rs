@@ -36,14 +36,6 @@
 /// assert_obj_safe!(inner::BasicTrait);
 /// ```
 ///
-/// The following example fails to compile because raw pointers cannot be sent
-///  between threads safely:
-///
-/// ```compile_fail
-/// # #[macro_use] extern crate static_assertions; fn main() {}
-/// assert_impl!(*const u8, Send);
-/// ```
-///
 /// The following example fails to compile because generics without
 /// `where Self: Sized` are not allowed in [object-safe][object] trait methods:
 ///
@@ -68,8 +60,12 @@
 /// ```
 ///
 /// [object]: https://doc.rust-lang.org/book/ch17-02-trait-objects.html#object-safety-is-required-for-trait-objects
-#[macro_export]
+#[macro_export(local_inner_macros)]
 macro_rules! assert_obj_safe {
+    // Labels were required on stable Rust before `const _` and are ignored.
+    ($label:ident; $($rest:tt)*) => {
+        assert_obj_safe!($($rest)*);
+    };
     ($($xs:path),+ $(,)?) => {
         $(const _: Option<&$xs> = None;)+
     };
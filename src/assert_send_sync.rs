@@ -0,0 +1,39 @@
+/// Asserts that types are both [`Send`] and [`Sync`].
+///
+/// This is shorthand for the most common use of [`assert_impl_all!`]. Unsized
+/// types such as `[u8]` and `dyn Trait + Send + Sync` are accepted as well.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// use std::sync::{Arc, Mutex};
+///
+/// struct Config {
+///     name: String,
+/// }
+///
+/// assert_send_sync!(Config);
+/// assert_send_sync!(Arc<Mutex<Config>>, [u8], str);
+/// ```
+///
+/// The following example fails to compile because [`Rc`] is neither [`Send`]
+/// nor [`Sync`]:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// use std::rc::Rc;
+///
+/// assert_send_sync!(Rc<u8>);
+/// ```
+///
+/// [`assert_impl_all!`]: macro.assert_impl_all.html
+/// [`Rc`]: https://doc.rust-lang.org/std/rc/struct.Rc.html
+/// [`Send`]: https://doc.rust-lang.org/std/marker/trait.Send.html
+/// [`Sync`]: https://doc.rust-lang.org/std/marker/trait.Sync.html
+#[macro_export(local_inner_macros)]
+macro_rules! assert_send_sync {
+    ($($t:ty),+ $(,)?) => {
+        $(assert_impl_all!($t: $crate::_core::marker::Send, $crate::_core::marker::Sync);)+
+    };
+}
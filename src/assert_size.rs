@@ -0,0 +1,349 @@
+/// Asserts that types are zero-sized.
+///
+/// This is useful for ensuring that marker types and tags do not take up any
+/// space, such as when they are embedded in other types.
+///
+/// See [`assert_non_zero_sized!`] for achieving the opposite effect.
+///
+/// # Examples
+///
+/// Unit structs and [`PhantomData`] take up no space:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// use std::marker::PhantomData;
+///
+/// struct PhantomTag;
+///
+/// assert_zero_sized!(PhantomTag, (), PhantomData<u8>, [u64; 0]);
+/// ```
+///
+/// The following example fails to compile because `u8` takes up a byte:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// assert_zero_sized!((), u8);
+/// ```
+///
+/// [`assert_non_zero_sized!`]: macro.assert_non_zero_sized.html
+/// [`PhantomData`]: https://doc.rust-lang.org/std/marker/struct.PhantomData.html
+#[macro_export(local_inner_macros)]
+macro_rules! assert_zero_sized {
+    ($($t:ty),+ $(,)?) => {
+        $(_const_assert!($crate::_core::mem::size_of::<$t>() == 0);)+
+    };
+}
+
+/// Asserts that types are **not** zero-sized.
+///
+/// This guards against types accidentally becoming zero-sized, such as when
+/// all of their fields are removed.
+///
+/// See [`assert_zero_sized!`] for achieving the opposite effect.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// struct RealData {
+///     value: u32,
+/// }
+///
+/// assert_non_zero_sized!(RealData, u8, (u8, ()));
+/// ```
+///
+/// The following example fails to compile because the unit type takes up no
+/// space:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// assert_non_zero_sized!(u8, ());
+/// ```
+///
+/// [`assert_zero_sized!`]: macro.assert_zero_sized.html
+#[macro_export(local_inner_macros)]
+macro_rules! assert_non_zero_sized {
+    ($($t:ty),+ $(,)?) => {
+        $(_const_assert!($crate::_core::mem::size_of::<$t>() != 0);)+
+    };
+}
+
+/// Asserts that the size of a type is exactly a constant.
+///
+/// This is the counterpart to [`assert_eq_size!`] for sizes known in bytes,
+/// such as those of a wire format or a foreign ABI.
+///
+/// Related:
+/// - [`assert_size_le!`]
+/// - [`assert_size_lt!`]
+/// - [`assert_size_ge!`]
+/// - [`assert_size_gt!`]
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// #[repr(C)]
+/// struct Header {
+///     magic: u32,
+///     len: u32,
+///     flags: [u8; 8],
+/// }
+///
+/// assert_size!(Header, 16);
+/// assert_size!((), 0);
+/// ```
+///
+/// A message can be provided to explain the size, which is reported if the
+/// assertion fails. This requires Rust 1.57 or later:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// # struct Header([u8; 16]);
+/// assert_size!(Header, 16, "headers are read directly from the wire");
+/// ```
+///
+/// The following example fails to compile because the header is one byte
+/// too large:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// struct Header {
+///     magic: [u8; 4],
+///     len: [u8; 4],
+///     flags: [u8; 9],
+/// }
+///
+/// assert_size!(Header, 16);
+/// ```
+///
+/// [`assert_eq_size!`]: macro.assert_eq_size.html
+/// [`assert_size_le!`]: macro.assert_size_le.html
+/// [`assert_size_lt!`]: macro.assert_size_lt.html
+/// [`assert_size_ge!`]: macro.assert_size_ge.html
+/// [`assert_size_gt!`]: macro.assert_size_gt.html
+#[macro_export(local_inner_macros)]
+macro_rules! assert_size {
+    ($t:ty, $n:expr, $msg:literal $(,)?) => {
+        const _: () = $crate::_core::assert!($crate::_core::mem::size_of::<$t>() == $n, "{}", $msg);
+    };
+    ($t:ty, $n:expr $(,)?) => {
+        _const_assert!($crate::_core::mem::size_of::<$t>() == $n);
+    };
+}
+
+/// Asserts that the size of a type is less than or equal to a constant.
+///
+/// Related:
+/// - [`assert_size!`]
+/// - [`assert_size_lt!`]
+/// - [`assert_size_ge!`]
+/// - [`assert_size_gt!`]
+///
+/// # Examples
+///
+/// This is useful for keeping types within a byte budget, such as on embedded
+/// targets:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// struct Packet {
+///     header: [u8; 8],
+///     payload: [u8; 56],
+/// }
+///
+/// assert_size_le!(Packet, 64);
+/// assert_size_le!((), 0);
+/// ```
+///
+/// A message can be provided to explain the bound, which is reported if the
+/// assertion fails. This requires Rust 1.57 or later:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// # struct Packet([u8; 64]);
+/// assert_size_le!(Packet, 64, "packets must fit in a single frame");
+/// ```
+///
+/// The following example fails to compile because the packet has outgrown its
+/// budget:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// struct Packet {
+///     header: [u8; 8],
+///     payload: [u8; 64],
+/// }
+///
+/// assert_size_le!(Packet, 64);
+/// ```
+///
+/// [`assert_size_lt!`]: macro.assert_size_lt.html
+/// [`assert_size_ge!`]: macro.assert_size_ge.html
+/// [`assert_size_gt!`]: macro.assert_size_gt.html
+/// [`assert_size!`]: macro.assert_size.html
+#[macro_export(local_inner_macros)]
+macro_rules! assert_size_le {
+    ($t:ty, $n:expr, $msg:literal $(,)?) => {
+        const _: () = $crate::_core::assert!($crate::_core::mem::size_of::<$t>() <= $n, "{}", $msg);
+    };
+    ($t:ty, $n:expr $(,)?) => {
+        _const_assert!($crate::_core::mem::size_of::<$t>() <= $n);
+    };
+}
+
+/// Asserts that the size of a type is less than a constant.
+///
+/// Related:
+/// - [`assert_size!`]
+/// - [`assert_size_le!`]
+/// - [`assert_size_ge!`]
+/// - [`assert_size_gt!`]
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// assert_size_lt!(u32, 8);
+/// assert_size_lt!(u32, 8, "`u32` must be smaller than `u64`");
+/// ```
+///
+/// The following example fails to compile because a type is never smaller
+/// than its own size:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// assert_size_lt!(u64, 8);
+/// ```
+///
+/// [`assert_size_le!`]: macro.assert_size_le.html
+/// [`assert_size_ge!`]: macro.assert_size_ge.html
+/// [`assert_size_gt!`]: macro.assert_size_gt.html
+/// [`assert_size!`]: macro.assert_size.html
+#[macro_export(local_inner_macros)]
+macro_rules! assert_size_lt {
+    ($t:ty, $n:expr, $msg:literal $(,)?) => {
+        const _: () = $crate::_core::assert!($crate::_core::mem::size_of::<$t>() < $n, "{}", $msg);
+    };
+    ($t:ty, $n:expr $(,)?) => {
+        _const_assert!($crate::_core::mem::size_of::<$t>() < $n);
+    };
+}
+
+/// Asserts that the size of a type is greater than or equal to a constant.
+///
+/// Related:
+/// - [`assert_size!`]
+/// - [`assert_size_le!`]
+/// - [`assert_size_lt!`]
+/// - [`assert_size_gt!`]
+///
+/// # Examples
+///
+/// This can ensure that a buffer type is large enough to hold some data:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// struct Buffer([u8; 4096]);
+///
+/// assert_size_ge!(Buffer, 4096);
+/// assert_size_ge!(Buffer, 1024, "buffers must hold a whole record");
+/// ```
+///
+/// The following example fails to compile because `u16` is only 2 bytes:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// assert_size_ge!(u16, 4);
+/// ```
+///
+/// [`assert_size_le!`]: macro.assert_size_le.html
+/// [`assert_size_lt!`]: macro.assert_size_lt.html
+/// [`assert_size_gt!`]: macro.assert_size_gt.html
+/// [`assert_size!`]: macro.assert_size.html
+#[macro_export(local_inner_macros)]
+macro_rules! assert_size_ge {
+    ($t:ty, $n:expr, $msg:literal $(,)?) => {
+        const _: () = $crate::_core::assert!($crate::_core::mem::size_of::<$t>() >= $n, "{}", $msg);
+    };
+    ($t:ty, $n:expr $(,)?) => {
+        _const_assert!($crate::_core::mem::size_of::<$t>() >= $n);
+    };
+}
+
+/// Asserts that the size of a type is greater than a constant.
+///
+/// Related:
+/// - [`assert_size!`]
+/// - [`assert_size_le!`]
+/// - [`assert_size_lt!`]
+/// - [`assert_size_ge!`]
+///
+/// # Examples
+///
+/// This can ensure that a type is not zero-sized:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// assert_size_gt!(u8, 0);
+/// assert_size_gt!([u16; 4], 4, "arrays are not packed");
+/// ```
+///
+/// The following example fails to compile because the unit type takes up no
+/// space:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// assert_size_gt!((), 0);
+/// ```
+///
+/// [`assert_size_le!`]: macro.assert_size_le.html
+/// [`assert_size_lt!`]: macro.assert_size_lt.html
+/// [`assert_size_ge!`]: macro.assert_size_ge.html
+/// [`assert_size!`]: macro.assert_size.html
+#[macro_export(local_inner_macros)]
+macro_rules! assert_size_gt {
+    ($t:ty, $n:expr, $msg:literal $(,)?) => {
+        const _: () = $crate::_core::assert!($crate::_core::mem::size_of::<$t>() > $n, "{}", $msg);
+    };
+    ($t:ty, $n:expr $(,)?) => {
+        _const_assert!($crate::_core::mem::size_of::<$t>() > $n);
+    };
+}
+
+/// Asserts that pointers are the given number of bits wide.
+///
+/// This is useful for code that relies on the size of [`usize`] or pointers,
+/// such as when storing them in fixed-size integers. It reads better than
+/// scattering `#[cfg(target_pointer_width = "...")]` throughout the code.
+///
+/// The width is that of the target being compiled for, rather than that of the
+/// host doing the compiling. Cross-compiling for a target with a different
+/// pointer width therefore fails even if building natively works.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// #[cfg(target_pointer_width = "64")]
+/// assert_pointer_width!(64);
+///
+/// #[cfg(target_pointer_width = "32")]
+/// assert_pointer_width!(32);
+/// ```
+///
+/// The following example fails to compile because no target has pointers that
+/// are 128 bits wide:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// assert_pointer_width!(128);
+/// ```
+///
+/// [`usize`]: https://doc.rust-lang.org/std/primitive.usize.html
+#[macro_export(local_inner_macros)]
+macro_rules! assert_pointer_width {
+    ($n:expr $(,)?) => {
+        _const_assert!($crate::_core::mem::size_of::<usize>() * 8 == $n);
+    };
+}
@@ -28,16 +28,60 @@
 /// assert_trait_sub_all!(PartialOrd: Eq);
 /// ```
 ///
+/// Generic traits work as well:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// trait Parent<T: ?Sized> {}
+/// trait Child<T: ?Sized>: Parent<T> {}
+///
+/// assert_trait_sub_all!(Child<str>: Parent<str>);
+/// ```
+///
+/// Only [`Sized`] implementors of the sub-trait are considered, so any trait
+/// passes as a child of [`Sized`]. Prefixing the sub-trait with `?Sized +`
+/// considers unsized implementors too. This fails to compile because [`Debug`]
+/// can be implemented for types that aren't [`Sized`]:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// assert_trait_sub_all!(?Sized + std::fmt::Debug: Sized);
+/// ```
+///
+/// Without the prefix, blanket implementations that require [`Sized`] are
+/// taken into account, such as [`Iterator`] being a child of [`IntoIterator`]:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// assert_trait_sub_all!(Iterator: IntoIterator);
+/// ```
+///
 /// [`assert_trait_super_all!`]: macro.assert_trait_super_all.html
 ///
-/// [`Copy`]:       https://doc.rust-lang.org/std/marker/trait.Copy.html
-/// [`Clone`]:      https://doc.rust-lang.org/std/clone/trait.Clone.html
-/// [`Ord`]:        https://doc.rust-lang.org/std/cmp/trait.Ord.html
-/// [`PartialOrd`]: https://doc.rust-lang.org/std/cmp/trait.PartialOrd.html
-/// [`Eq`]:         https://doc.rust-lang.org/std/cmp/trait.Eq.html
-/// [`PartialEq`]:  https://doc.rust-lang.org/std/cmp/trait.PartialEq.html
+/// [`Copy`]:         https://doc.rust-lang.org/std/marker/trait.Copy.html
+/// [`Clone`]:        https://doc.rust-lang.org/std/clone/trait.Clone.html
+/// [`Ord`]:          https://doc.rust-lang.org/std/cmp/trait.Ord.html
+/// [`PartialOrd`]:   https://doc.rust-lang.org/std/cmp/trait.PartialOrd.html
+/// [`Eq`]:           https://doc.rust-lang.org/std/cmp/trait.Eq.html
+/// [`PartialEq`]:    https://doc.rust-lang.org/std/cmp/trait.PartialEq.html
+/// [`Debug`]:        https://doc.rust-lang.org/std/fmt/trait.Debug.html
+/// [`Sized`]:        https://doc.rust-lang.org/std/marker/trait.Sized.html
+/// [`Iterator`]:     https://doc.rust-lang.org/std/iter/trait.Iterator.html
+/// [`IntoIterator`]: https://doc.rust-lang.org/std/iter/trait.IntoIterator.html
 #[macro_export]
 macro_rules! assert_trait_sub_all {
+    (?Sized + $sub:path: $($super:path),+ $(,)?) => {
+        const _: () = {
+            $({
+                #[allow(non_camel_case_types)]
+                trait __Impl_Implication: $super {}
+
+                // `?Sized` ensures that `Sized` is only satisfied if it's
+                // implied by `$sub`.
+                impl<T: ?Sized + $sub> __Impl_Implication for T {}
+            })+
+        };
+    };
     ($sub:path: $($super:path),+ $(,)?) => {
         const _: () = {
             // One scope per super-trait.
@@ -55,6 +99,11 @@ macro_rules! assert_trait_sub_all {
 
 /// Asserts that the trait is a parent of all of the other traits.
 ///
+/// This is the inverse of [`assert_trait_sub_all!`]. Rather than checking that
+/// one trait requires many others, this checks that many traits require one.
+/// It's useful for guaranteeing that downstream traits still extend a trait
+/// that you own.
+///
 /// Related:
 /// - [`assert_trait_sub_all!`]
 ///
@@ -103,3 +152,150 @@ macro_rules! assert_trait_super_all {
         $(assert_trait_sub_all!($sub: $super);)+
     };
 }
+
+/// Asserts that a trait object can be upcast to a trait object of a supertrait.
+///
+/// This coerces a `&dyn Sub` to a `&dyn Super`, which requires Rust 1.86 or
+/// later. Unlike [`assert_trait_sub_all!`], which checks a `where` bound, this
+/// exercises the coercion itself, so it also ensures that both traits are
+/// [object safe].
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// use std::fmt::Debug;
+///
+/// trait Shape: Debug {
+///     fn area(&self) -> f64;
+/// }
+///
+/// trait Polygon: Shape + Send {
+///     fn sides(&self) -> usize;
+/// }
+///
+/// assert_upcast!(dyn Polygon -> dyn Shape);
+/// assert_upcast!(dyn Polygon -> dyn Debug);
+/// assert_upcast!(dyn Polygon + Sync -> dyn Shape + Send + Sync);
+/// ```
+///
+/// The following example fails to compile because `Shape` is not a supertrait
+/// of `Polygon`:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// trait Shape {}
+/// trait Polygon {}
+///
+/// assert_upcast!(dyn Polygon -> dyn Shape);
+/// ```
+///
+/// [`assert_trait_sub_all!`]: macro.assert_trait_sub_all.html
+/// [object safe]: https://doc.rust-lang.org/reference/items/traits.html#object-safety
+#[macro_export(local_inner_macros)]
+macro_rules! assert_upcast {
+    ($($rest:tt)+) => {
+        _assert_upcast!([] $($rest)+);
+    };
+}
+
+#[doc(hidden)]
+#[macro_export(local_inner_macros)]
+macro_rules! _assert_upcast {
+    // `->` can't follow a type fragment, so the source type is taken one token
+    // at a time.
+    ([$($sub:tt)+] -> $sup:ty) => {
+        const _: fn() = || {
+            fn upcast<'a>(sub: &'a ($($sub)+)) -> &'a ($sup) {
+                sub
+            }
+        };
+    };
+    ([$($sub:tt)*] $t:tt $($rest:tt)+) => {
+        _assert_upcast!([$($sub)* $t] $($rest)+);
+    };
+}
+
+/// Asserts that the type implements a trait with the given method.
+///
+/// The method is referred to through the trait as a function item, like
+/// `<T as Trait>::method`. This breaks the build if the method is removed or
+/// renamed, or if the type no longer implements the trait. Unlike calling the
+/// method, this never resolves to an inherent method of the same name or to
+/// the method of a type that `T` dereferences to.
+///
+/// Associated functions without a receiver, such as [`Default::default`], can
+/// be asserted too. Methods with generic parameters of their own can't be
+/// referred to without specifying them, so they are not supported.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// trait Shape {
+///     fn area(&self) -> f64;
+///     fn name() -> &'static str;
+/// }
+///
+/// struct Square(f64);
+///
+/// impl Shape for Square {
+///     fn area(&self) -> f64 { self.0 * self.0 }
+///     fn name() -> &'static str { "square" }
+/// }
+///
+/// assert_trait_method!(Square: Shape::area);
+/// assert_trait_method!(Square: Shape::name);
+/// assert_trait_method!(String: std::str::FromStr::from_str);
+/// assert_trait_method!(str: AsRef<[u8]>::as_ref);
+/// ```
+///
+/// The following example fails to compile because `Shape` has no `perimeter`
+/// method:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// # trait Shape { fn area(&self) -> f64; }
+/// # struct Square(f64);
+/// # impl Shape for Square { fn area(&self) -> f64 { self.0 * self.0 } }
+/// assert_trait_method!(Square: Shape::perimeter);
+/// ```
+///
+/// Inherent methods are not considered, even when they have the same name:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// trait Shape {}
+///
+/// struct Square(f64);
+///
+/// impl Square {
+///     fn area(&self) -> f64 { self.0 * self.0 }
+/// }
+///
+/// impl Shape for Square {}
+///
+/// assert_trait_method!(Square: Shape::area);
+/// ```
+///
+/// [`Default::default`]: https://doc.rust-lang.org/std/default/trait.Default.html#tymethod.default
+#[macro_export(local_inner_macros)]
+macro_rules! assert_trait_method {
+    ($type:ty: $($path:tt)+) => {
+        _assert_trait_method!([$type] [] $($path)+);
+    };
+}
+
+#[doc(hidden)]
+#[macro_export(local_inner_macros)]
+macro_rules! _assert_trait_method {
+    // The method is the last segment of the path, after the trait.
+    ([$type:ty] [$($trait:tt)+] :: $method:ident) => {
+        const _: () = {
+            let _ = <$type as $($trait)+>::$method;
+        };
+    };
+    ($type:tt [$($trait:tt)*] $t:tt $($rest:tt)+) => {
+        _assert_trait_method!($type [$($trait)* $t] $($rest)+);
+    };
+}
@@ -0,0 +1,68 @@
+/// Asserts that values of one type can be transmuted into another.
+///
+/// This combines [`assert_eq_size!`] and [`assert_no_drop!`], checking that
+/// the types have the same size and that neither needs to be dropped. Writing
+/// `align` after the types also checks that they have the same alignment with
+/// [`assert_eq_align!`], as is needed for transmuting references or pointers
+/// to them. This requires Rust 1.57 or later.
+///
+/// This is a necessary but not sufficient check for [`transmute`] to be
+/// sound. It says nothing about whether every bit pattern of one type is a
+/// valid value of the other, such as for `u32` and [`char`], nor about
+/// padding bytes or invariants upheld by either type. Those remain up to the
+/// `unsafe` code that does the transmuting.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// #[repr(transparent)]
+/// struct Meters(f64);
+///
+/// assert_transmute_safe!(u32, [u8; 4]);
+/// assert_transmute_safe!(Meters, u64, align);
+/// ```
+///
+/// The following example fails to compile because the types differ in size:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// assert_transmute_safe!(u32, [u8; 8]);
+/// ```
+///
+/// [`Vec`] has the same size as three `usize`s, but it fails to compile
+/// because it needs to be dropped:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// assert_transmute_safe!(Vec<u8>, [usize; 3]);
+/// ```
+///
+/// The following example fails to compile because `[u8; 4]` is only aligned to
+/// 1 byte:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// assert_transmute_safe!(u32, [u8; 4], align);
+/// ```
+///
+/// [`assert_eq_size!`]: macro.assert_eq_size.html
+/// [`assert_eq_align!`]: macro.assert_eq_align.html
+/// [`assert_no_drop!`]: macro.assert_no_drop.html
+/// [`transmute`]: https://doc.rust-lang.org/std/mem/fn.transmute.html
+/// [`char`]: https://doc.rust-lang.org/std/primitive.char.html
+/// [`Vec`]: https://doc.rust-lang.org/std/vec/struct.Vec.html
+#[macro_export(local_inner_macros)]
+macro_rules! assert_transmute_safe {
+    ($x:ty, $y:ty, align $(,)?) => {
+        assert_transmute_safe!($x, $y);
+        _assert_eq_align!($x, $y);
+    };
+    ($x:ty, $y:ty $(,)?) => {
+        _assert_eq_size!($x, $y);
+        assert_no_drop!(
+            $x, $y;
+            "types transmuted with `assert_transmute_safe!` must not need to be dropped"
+        );
+    };
+}
@@ -25,6 +25,14 @@
 /// # }
 /// ```
 ///
+/// Integer types of different widths are distinct, even though one can be
+/// losslessly converted into the other:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// assert_type_eq_all!(u8, u16);
+/// ```
+///
 /// The following example fails to compile because `String` and `str` do not
 /// refer to the same type:
 ///
@@ -87,6 +95,17 @@ macro_rules! assert_type_eq_all {
 /// assert_type_ne_all!(c_uchar, u8, u32);
 /// ```
 ///
+/// This is useful for catching a newtype that was accidentally turned into a
+/// type alias of the type it wraps:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// struct Meters(f64);
+/// type Feet = f64; // Oops! This should have been a newtype too.
+///
+/// assert_type_ne_all!(Meters, Feet, f64);
+/// ```
+///
 /// [`c_uchar`]: https://doc.rust-lang.org/std/os/raw/type.c_uchar.html
 /// [`u8`]: https://doc.rust-lang.org/std/primitive.u8.html
 #[macro_export]
@@ -99,3 +118,149 @@ macro_rules! assert_type_ne_all {
         };
     };
 }
+
+/// Asserts that function pointer types have the same signature.
+///
+/// This is useful when binding symbols loaded via [FFI] to function pointer
+/// types, ensuring that they match the signature expected by the other side.
+/// The ABI, argument types, return type, and `unsafe`-ness must all be equal.
+///
+/// This is equivalent to [`assert_type_eq_all!`], but conveys the intent of
+/// comparing signatures.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// type MyFn = unsafe extern "C" fn(u32) -> i32;
+///
+/// assert_fn_ptr_compatible!(MyFn, unsafe extern "C" fn(u32) -> i32);
+/// ```
+///
+/// The following example fails to compile because the function pointers use
+/// different ABIs:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// type MyFn = extern "C" fn(u32) -> i32;
+///
+/// assert_fn_ptr_compatible!(MyFn, fn(u32) -> i32);
+/// ```
+///
+/// Signatures with a different number of arguments are incompatible as well:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// type MyFn = extern "C" fn(u32) -> i32;
+///
+/// assert_fn_ptr_compatible!(MyFn, extern "C" fn(u32, u32) -> i32);
+/// ```
+///
+/// [`assert_type_eq_all!`]: macro.assert_type_eq_all.html
+/// [FFI]: https://en.wikipedia.org/wiki/Foreign_function_interface
+#[macro_export(local_inner_macros)]
+macro_rules! assert_fn_ptr_compatible {
+    ($x:ty, $($xs:ty),+ $(,)?) => {
+        assert_type_eq_all!($x, $($xs),+);
+    };
+}
+
+/// Asserts that a function or closure returns the given type, without calling
+/// it.
+///
+/// This is useful for ensuring that a builder's terminal method returns the
+/// concrete type that callers expect. Functions and closures with up to 12
+/// arguments are supported. Generic functions need their parameters specified,
+/// such as with `parse::<u8>`.
+///
+/// `unsafe` and `extern` functions don't implement [`FnOnce`], so they are not
+/// supported. Use [`assert_fn_ptr_compatible!`] with their full signature
+/// instead.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// fn checksum(data: &[u8]) -> u32 {
+///     // ...
+/// #   data.len() as u32
+/// }
+///
+/// struct Builder;
+/// struct Request;
+///
+/// impl Builder {
+///     fn build(self) -> Request {
+///         // ...
+/// #       Request
+///     }
+/// }
+///
+/// assert_return_type_eq!(checksum, u32);
+/// assert_return_type_eq!(Builder::build, Request);
+/// assert_return_type_eq!(str::len, usize);
+/// assert_return_type_eq!(|x: u8| Some(x), Option<u8>);
+/// ```
+///
+/// The types must be the same, not just convertible. The following example
+/// fails to compile because the function returns an `i32`:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// fn offset() -> i32 {
+///     // ...
+/// #   0
+/// }
+///
+/// assert_return_type_eq!(offset, u32);
+/// ```
+///
+/// [`FnOnce`]: https://doc.rust-lang.org/std/ops/trait.FnOnce.html
+/// [`assert_fn_ptr_compatible!`]: macro.assert_fn_ptr_compatible.html
+#[macro_export]
+macro_rules! assert_return_type_eq {
+    ($f:expr, $t:ty $(,)?) => {
+        const _: fn() = || {
+            // Only callable when `F` returns `T` for its argument types `A`.
+            fn assert_return_type_eq<F, A, T>(_: F)
+            where
+                F: $crate::_FnOutput<A, Output = T>,
+            {
+            }
+            assert_return_type_eq::<_, _, $t>($f);
+        };
+    };
+}
+
+/// The return type of a function or closure, for any number of arguments.
+///
+/// Only one impl can apply to any given function, so `Args` can be inferred.
+#[doc(hidden)]
+pub trait _FnOutput<Args> {
+    type Output;
+}
+
+macro_rules! impl_fn_output {
+    ($($a:ident)*) => {
+        impl<Func, R, $($a),*> _FnOutput<($($a,)*)> for Func
+        where
+            Func: FnOnce($($a),*) -> R,
+        {
+            type Output = R;
+        }
+    };
+}
+
+impl_fn_output!();
+impl_fn_output!(A);
+impl_fn_output!(A B);
+impl_fn_output!(A B C);
+impl_fn_output!(A B C D);
+impl_fn_output!(A B C D E);
+impl_fn_output!(A B C D E F);
+impl_fn_output!(A B C D E F G);
+impl_fn_output!(A B C D E F G H);
+impl_fn_output!(A B C D E F G H I);
+impl_fn_output!(A B C D E F G H I J);
+impl_fn_output!(A B C D E F G H I J K);
+impl_fn_output!(A B C D E F G H I J K L);
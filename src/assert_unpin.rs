@@ -0,0 +1,81 @@
+/// Asserts that types are [`Unpin`].
+///
+/// This is useful for futures and other types that are expected to be freely
+/// movable after being pinned.
+///
+/// See [`assert_not_unpin!`] for achieving the opposite effect.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// struct MyFuture {
+///     polled: bool,
+/// }
+///
+/// assert_unpin!(MyFuture, u8, Box<dyn std::future::Future<Output = ()>>);
+/// ```
+///
+/// The following example fails to compile because [`PhantomPinned`] opts out
+/// of [`Unpin`]:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// use std::marker::PhantomPinned;
+///
+/// struct SelfRef {
+///     data: [u8; 16],
+///     ptr: *const u8,
+///     _pin: PhantomPinned,
+/// }
+///
+/// assert_unpin!(SelfRef);
+/// ```
+///
+/// [`assert_not_unpin!`]: macro.assert_not_unpin.html
+/// [`PhantomPinned`]: https://doc.rust-lang.org/std/marker/struct.PhantomPinned.html
+/// [`Unpin`]: https://doc.rust-lang.org/std/marker/trait.Unpin.html
+#[macro_export(local_inner_macros)]
+macro_rules! assert_unpin {
+    ($($t:ty),+ $(,)?) => {
+        $(assert_impl_all!($t: $crate::_core::marker::Unpin);)+
+    };
+}
+
+/// Asserts that types are **not** [`Unpin`].
+///
+/// This is useful for self-referential types, which must never be moved once
+/// pinned.
+///
+/// See [`assert_unpin!`] for achieving the opposite effect.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// use std::marker::PhantomPinned;
+///
+/// struct SelfRef {
+///     data: [u8; 16],
+///     ptr: *const u8,
+///     _pin: PhantomPinned,
+/// }
+///
+/// assert_not_unpin!(SelfRef, PhantomPinned);
+/// ```
+///
+/// The following example fails to compile because `u8` is [`Unpin`]:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// assert_not_unpin!(u8);
+/// ```
+///
+/// [`assert_unpin!`]: macro.assert_unpin.html
+/// [`Unpin`]: https://doc.rust-lang.org/std/marker/trait.Unpin.html
+#[macro_export(local_inner_macros)]
+macro_rules! assert_not_unpin {
+    ($($t:ty),+ $(,)?) => {
+        $(assert_not_impl_any!($t: $crate::_core::marker::Unpin);)+
+    };
+}
@@ -0,0 +1,33 @@
+/// Asserts that constant byte slices are valid UTF-8.
+///
+/// This is useful for byte constants that are later converted into strings,
+/// such as those generated for protocols or file formats. It requires Rust
+/// 1.63 or later, which made [`str::from_utf8`] usable in constants.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// const MAGIC: &[u8] = b"RIFF";
+/// const GREETING: &[u8] = "¡Hola!".as_bytes();
+///
+/// assert_valid_utf8!(MAGIC, GREETING, b"");
+/// ```
+///
+/// The following example fails to compile because `0xFF` never appears in
+/// UTF-8:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// const BOM: &[u8] = &[0xFF, 0xFE];
+///
+/// assert_valid_utf8!(BOM);
+/// ```
+///
+/// [`str::from_utf8`]: https://doc.rust-lang.org/core/str/fn.from_utf8.html
+#[macro_export(local_inner_macros)]
+macro_rules! assert_valid_utf8 {
+    ($($x:expr),+ $(,)?) => {
+        $(_const_assert!($crate::_core::str::from_utf8($x).is_ok());)+
+    };
+}
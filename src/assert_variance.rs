@@ -0,0 +1,74 @@
+/// Asserts that a type is [covariant] over the lifetime `'a`.
+///
+/// A type is covariant over `'a` when a value with a longer lifetime can be
+/// used where a shorter one is expected, such as `&'static str` as `&'a str`.
+/// Changes to private fields can silently make a type invariant, which breaks
+/// users relying on this coercion and may affect the soundness of `unsafe`
+/// code.
+///
+/// The type must refer to the lifetime by the name `'a`.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// struct Token<'a> {
+///     text: &'a str,
+///     line: usize,
+/// }
+///
+/// assert_covariant!(Token<'a>);
+/// assert_covariant!(Option<&'a [u8]>);
+/// assert_covariant!(Box<[&'a str]>);
+/// ```
+///
+/// The following example fails to compile because [`Cell`] is invariant over
+/// the type it contains:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// use std::cell::Cell;
+///
+/// struct Slot<'a> {
+///     value: Cell<&'a str>,
+/// }
+///
+/// assert_covariant!(Slot<'a>);
+/// ```
+///
+/// Likewise, function pointers are [contravariant] over their arguments, so the
+/// following fails to compile as well:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// assert_covariant!(fn(&'a str));
+/// ```
+///
+/// # Invariance
+///
+/// There is no macro for asserting that a type is _not_ covariant. Lifetimes
+/// are only checked after types and traits have been resolved, so no assertion
+/// can depend on a coercion failing. For that, a `compile_fail` doctest as
+/// above can be used instead.
+///
+/// [covariant]: https://doc.rust-lang.org/nomicon/subtyping.html#variance
+/// [contravariant]: https://doc.rust-lang.org/nomicon/subtyping.html#variance
+/// [`Cell`]: https://doc.rust-lang.org/std/cell/struct.Cell.html
+#[macro_export]
+macro_rules! assert_covariant {
+    ($t:ty $(,)?) => {
+        const _: () = {
+            // Lifetimes are not hygienic, so `'a` refers to that within `$t`.
+            type Covariant<'a> = $t;
+
+            // Only compiles when `$t` with `'long` is a subtype of `$t` with
+            // `'short`.
+            #[allow(dead_code)]
+            fn assert_covariant<'short, 'long: 'short>(
+                x: Covariant<'long>,
+            ) -> Covariant<'short> {
+                x
+            }
+        };
+    };
+}
@@ -1,7 +1,7 @@
 /// Asserts that constant expressions evaluate to `true`.
 ///
 /// Constant expressions can be ensured to have certain properties via this
-/// macro If the expression evaluates to `false`, the file will fail to compile.
+/// macro. If the expression evaluates to `false`, the file will fail to compile.
 /// This is synonymous to [`static_assert` in C++][static_assert].
 ///
 /// # Alternatives
@@ -22,6 +22,15 @@
 /// const_assert!(VALUE >= 2);
 /// ```
 ///
+/// Any `const`-evaluable `bool` can be used, including calls to `const fn`s:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// const SIZE: usize = 1 << 4;
+///
+/// const_assert!(SIZE > 0 && SIZE.is_power_of_two());
+/// ```
+///
 /// Inputs are type-checked as booleans:
 ///
 /// ```compile_fail
@@ -47,10 +56,105 @@
 /// const_assert!(5 * 5 == 5);
 /// ```
 ///
+/// # Generic Parameters
+///
+/// The assertion expands to an item, which can't refer to the generic
+/// parameters of an enclosing function or `impl`. Wrapping the expression in
+/// `const { ... }` instead asserts it within an [inline `const`] block, which
+/// can. This must be placed where statements are allowed, such as within a
+/// method, and requires Rust 1.79 or later:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// struct RingBuffer<const N: usize> {
+///     items: [u32; N],
+///     head: usize,
+/// }
+///
+/// impl<const N: usize> RingBuffer<N> {
+///     fn new() -> Self {
+///         const_assert!(const { N >= 1 && N.is_power_of_two() });
+///         RingBuffer { items: [0; N], head: 0 }
+///     }
+/// }
+///
+/// let buffer = RingBuffer::<8>::new();
+/// # let _ = (buffer.items, buffer.head);
+/// ```
+///
+/// The expression is only evaluated for the parameters that the function is
+/// used with, once code is generated for it. As such, a failure is not
+/// reported by `cargo check`. The following example fails to build because
+/// `RingBuffer::<0>` can't hold any items:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions;
+/// # struct RingBuffer<const N: usize> { items: [u32; N] }
+/// # impl<const N: usize> RingBuffer<N> {
+/// #     fn new() -> Self {
+/// #         const_assert!(const { N >= 1 && N.is_power_of_two() });
+/// #         RingBuffer { items: [0; N] }
+/// #     }
+/// # }
+/// fn main() {
+///     let buffer = RingBuffer::<0>::new();
+/// #   let _ = buffer.items;
+/// }
+/// ```
+///
+/// # Runtime Assertions
+///
+/// With the `runtime` feature flag, this and the other `const_assert_*` macros
+/// become [`debug_assert!`]s instead, except for the `const { ... }` form. See
+/// the [crate docs][runtime] for details.
+///
+/// [inline `const`]: https://doc.rust-lang.org/reference/expressions/block-expr.html#const-blocks
 /// [static_assert]: http://en.cppreference.com/w/cpp/language/static_assert
-#[macro_export]
+/// [`debug_assert!`]: https://doc.rust-lang.org/std/macro.debug_assert.html
+/// [runtime]: index.html#runtime-assertions
+#[macro_export(local_inner_macros)]
 macro_rules! const_assert {
+    // Labels were required on stable Rust before `const _` and are ignored.
+    ($label:ident; $($rest:tt)*) => {
+        const_assert!($($rest)*);
+    };
+    (const { $x:expr } $(,)?) => {
+        let _ = const { $crate::_core::assert!($x) };
+    };
     ($x:expr $(,)?) => {
+        _runtime_const_assert!($x);
+    };
+}
+
+/// Emits `const_assert!`, which the `runtime` feature flag turns into a
+/// `debug_assert!`.
+///
+/// This is defined here rather than via `#[cfg]` within the expansion, which
+/// would check the features of the calling crate instead.
+#[doc(hidden)]
+#[cfg(not(feature = "runtime"))]
+#[macro_export(local_inner_macros)]
+macro_rules! _runtime_const_assert {
+    ($x:expr) => {
+        _const_assert!($x);
+    };
+}
+
+#[doc(hidden)]
+#[cfg(feature = "runtime")]
+#[macro_export(local_inner_macros)]
+macro_rules! _runtime_const_assert {
+    ($x:expr) => {
+        debug_const_assert!($x);
+    };
+}
+
+/// Asserts `const_assert!` at compile-time regardless of the `runtime` feature
+/// flag, for use by other assertions.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _const_assert {
+    ($x:expr) => {
         #[allow(unknown_lints, eq_op)]
         const _: [(); 0 - !{ const ASSERT: bool = $x; ASSERT } as usize] = [];
     };
@@ -69,16 +173,40 @@ macro_rules! const_assert {
 /// const_assert_eq!(TWO * TWO, TWO + TWO);
 /// ```
 ///
+/// Any number of values can be compared against the first one:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// const HEADER_LEN: usize = 8;
+///
+/// const_assert_eq!(HEADER_LEN, 8, 2 * 4, 1 << 3);
+/// ```
+///
 /// Just because 2 × 2 = 2 + 2 doesn't mean it holds true for other numbers:
 ///
 /// ```compile_fail
 /// # #[macro_use] extern crate static_assertions; fn main() {}
 /// const_assert_eq!(4 + 4, 4 * 4);
 /// ```
+///
+/// The assertion fails if _any_ of the values differ:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// const_assert_eq!(8, 2 * 4, 3 * 3);
+/// ```
+///
+/// The error points to the assertion, but does not include the values that
+/// were compared. Formatting a value into an error requires a `const` panic
+/// with a formatted message, which stable Rust does not support.
 #[macro_export(local_inner_macros)]
 macro_rules! const_assert_eq {
-    ($x:expr, $y:expr $(,)?) => {
-        const_assert!($x == $y);
+    // Labels were required on stable Rust before `const _` and are ignored.
+    ($label:ident; $($rest:tt)*) => {
+        const_assert_eq!($($rest)*);
+    };
+    ($x:expr, $($y:expr),+ $(,)?) => {
+        $(const_assert!($x == $y);)+
     };
 }
 
@@ -95,6 +223,14 @@ macro_rules! const_assert_eq {
 /// const_assert_ne!(NUM * NUM, 64);
 /// ```
 ///
+/// When given more values, the first one must differ from each of the others:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// # const NUM: usize = 32;
+/// const_assert_ne!(NUM, 0, 1, NUM + 1);
+/// ```
+///
 /// The following example fails to compile because 2 is magic and 2 × 2 = 2 + 2:
 ///
 /// ```compile_fail
@@ -103,7 +239,246 @@ macro_rules! const_assert_eq {
 /// ```
 #[macro_export(local_inner_macros)]
 macro_rules! const_assert_ne {
+    // Labels were required on stable Rust before `const _` and are ignored.
+    ($label:ident; $($rest:tt)*) => {
+        const_assert_ne!($($rest)*);
+    };
+    ($x:expr, $($y:expr),+ $(,)?) => {
+        $(const_assert!($x != $y);)+
+    };
+}
+
+/// Asserts that a constant is less than another.
+///
+/// Related:
+/// - [`const_assert_le!`]
+/// - [`const_assert_gt!`]
+/// - [`const_assert_ge!`]
+///
+/// # Examples
+///
+/// This works as a shorthand for `const_assert!(a < b)`:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// const USED: usize = 48;
+/// const CAPACITY: usize = 64;
+///
+/// const_assert_lt!(USED, CAPACITY);
+/// ```
+///
+/// The following example fails to compile because a value is never less than
+/// itself:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// const_assert_lt!(64, 64);
+/// ```
+///
+/// [`const_assert_le!`]: macro.const_assert_le.html
+/// [`const_assert_gt!`]: macro.const_assert_gt.html
+/// [`const_assert_ge!`]: macro.const_assert_ge.html
+#[macro_export(local_inner_macros)]
+macro_rules! const_assert_lt {
+    ($x:expr, $y:expr $(,)?) => {
+        const_assert!($x < $y);
+    };
+}
+
+/// Asserts that a constant is less than or equal to another.
+///
+/// Related:
+/// - [`const_assert_lt!`]
+/// - [`const_assert_gt!`]
+/// - [`const_assert_ge!`]
+///
+/// # Examples
+///
+/// This works as a shorthand for `const_assert!(a <= b)`, making it useful for
+/// ensuring that a buffer stays within its capacity:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// const USED: usize = 64;
+/// const CAPACITY: usize = 64;
+///
+/// const_assert_le!(USED, CAPACITY);
+/// ```
+///
+/// Associated constants work too:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// struct Foo;
+///
+/// impl Foo {
+///     const MIN: i32 = -1;
+///     const MAX: i32 = 1;
+/// }
+///
+/// const_assert_le!(Foo::MIN, Foo::MAX);
+/// ```
+///
+/// The following example fails to compile because the buffer has outgrown its
+/// capacity:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// const USED: usize = 65;
+/// const CAPACITY: usize = 64;
+///
+/// const_assert_le!(USED, CAPACITY);
+/// ```
+///
+/// [`const_assert_lt!`]: macro.const_assert_lt.html
+/// [`const_assert_gt!`]: macro.const_assert_gt.html
+/// [`const_assert_ge!`]: macro.const_assert_ge.html
+#[macro_export(local_inner_macros)]
+macro_rules! const_assert_le {
+    ($x:expr, $y:expr $(,)?) => {
+        const_assert!($x <= $y);
+    };
+}
+
+/// Asserts that a constant is greater than another.
+///
+/// Related:
+/// - [`const_assert_lt!`]
+/// - [`const_assert_le!`]
+/// - [`const_assert_ge!`]
+///
+/// # Examples
+///
+/// This works as a shorthand for `const_assert!(a > b)`:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// const_assert_gt!(i32::max_value(), 0);
+/// ```
+///
+/// The following example fails to compile because a value is never greater
+/// than itself:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// const_assert_gt!(0, 0);
+/// ```
+///
+/// [`const_assert_lt!`]: macro.const_assert_lt.html
+/// [`const_assert_le!`]: macro.const_assert_le.html
+/// [`const_assert_ge!`]: macro.const_assert_ge.html
+#[macro_export(local_inner_macros)]
+macro_rules! const_assert_gt {
     ($x:expr, $y:expr $(,)?) => {
-        const_assert!($x != $y);
+        const_assert!($x > $y);
+    };
+}
+
+/// Asserts that a constant is greater than or equal to another.
+///
+/// Related:
+/// - [`const_assert_lt!`]
+/// - [`const_assert_le!`]
+/// - [`const_assert_gt!`]
+///
+/// # Examples
+///
+/// This works as a shorthand for `const_assert!(a >= b)`:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// const ALIGN: usize = 8;
+///
+/// const_assert_ge!(ALIGN, 8);
+/// const_assert_ge!(ALIGN, 4);
+/// ```
+///
+/// The following example fails to compile because 4 is less than 8:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// const_assert_ge!(4, 8);
+/// ```
+///
+/// [`const_assert_lt!`]: macro.const_assert_lt.html
+/// [`const_assert_le!`]: macro.const_assert_le.html
+/// [`const_assert_gt!`]: macro.const_assert_gt.html
+#[macro_export(local_inner_macros)]
+macro_rules! const_assert_ge {
+    ($x:expr, $y:expr $(,)?) => {
+        const_assert!($x >= $y);
+    };
+}
+
+/// Asserts that _all_ constant expressions evaluate to `true`.
+///
+/// Each expression is asserted separately, so a failure points to the specific
+/// expression that evaluated to `false`.
+///
+/// Related:
+/// - [`const_assert_any!`]
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// const WIDTH: usize = 640;
+/// const HEIGHT: usize = 600;
+///
+/// const_assert_all!(WIDTH > 0, HEIGHT > 0, WIDTH % 8 == 0);
+/// ```
+///
+/// The following example fails to compile because the height is not a multiple
+/// of 16:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// # const WIDTH: usize = 640;
+/// # const HEIGHT: usize = 600;
+/// const_assert_all!(WIDTH % 16 == 0, HEIGHT % 16 == 0);
+/// ```
+///
+/// [`const_assert_any!`]: macro.const_assert_any.html
+#[macro_export(local_inner_macros)]
+macro_rules! const_assert_all {
+    ($($x:expr),+ $(,)?) => {
+        $(const_assert!($x);)+
+    };
+}
+
+/// Asserts that _any_ constant expression evaluates to `true`.
+///
+/// Related:
+/// - [`const_assert_all!`]
+///
+/// # Examples
+///
+/// This is useful for ensuring that at least one of a set of options is
+/// enabled:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// const USE_TCP: bool = false;
+/// const USE_UDP: bool = true;
+///
+/// const_assert_any!(USE_TCP, USE_UDP);
+/// ```
+///
+/// The following example fails to compile because none of the options are
+/// enabled:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// const USE_TCP: bool = false;
+/// const USE_UDP: bool = false;
+///
+/// const_assert_any!(USE_TCP, USE_UDP);
+/// ```
+///
+/// [`const_assert_all!`]: macro.const_assert_all.html
+#[macro_export(local_inner_macros)]
+macro_rules! const_assert_any {
+    ($($x:expr),+ $(,)?) => {
+        const_assert!($($x)||+);
     };
 }
@@ -0,0 +1,87 @@
+use core::marker::PhantomData;
+
+/// Asserts that the type implements the given auto traits, using only `const`
+/// items.
+///
+/// Unlike [`assert_impl_all!`], this does not define a function. Each trait is
+/// instead required by the type of a `const`, which is checked like that of any
+/// other `const` item. This only supports [`Send`], [`Sync`], and [`Unpin`],
+/// written as plain names. Use [`assert_impl_all!`] for all other traits.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// use std::sync::Mutex;
+///
+/// static COUNTER: Mutex<u32> = Mutex::new(0);
+///
+/// const_assert_impl!(Mutex<u32>: Send, Sync);
+/// const_assert_impl!(&'static str: Send, Sync, Unpin);
+/// ```
+///
+/// The following example fails to compile because [`Cell`] is not [`Sync`]:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// use std::cell::Cell;
+///
+/// const_assert_impl!(Cell<u32>: Send, Sync);
+/// ```
+///
+/// Other traits are rejected, even if they are implemented:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// const_assert_impl!(u32: Copy);
+/// ```
+///
+/// [`assert_impl_all!`]: macro.assert_impl_all.html
+/// [`Cell`]: https://doc.rust-lang.org/std/cell/struct.Cell.html
+/// [`Send`]: https://doc.rust-lang.org/std/marker/trait.Send.html
+/// [`Sync`]: https://doc.rust-lang.org/std/marker/trait.Sync.html
+/// [`Unpin`]: https://doc.rust-lang.org/std/marker/trait.Unpin.html
+#[macro_export(local_inner_macros)]
+macro_rules! const_assert_impl {
+    ($type:ty: $($bound:ident),+ $(,)?) => {
+        $(_const_assert_impl!($type, $bound);)+
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _const_assert_impl {
+    ($type:ty, Send) => {
+        const _: $crate::_AssertSend<$type> = $crate::_AssertSend { _marker: $crate::_core::marker::PhantomData };
+    };
+    ($type:ty, Sync) => {
+        const _: $crate::_AssertSync<$type> = $crate::_AssertSync { _marker: $crate::_core::marker::PhantomData };
+    };
+    ($type:ty, Unpin) => {
+        const _: $crate::_AssertUnpin<$type> = $crate::_AssertUnpin { _marker: $crate::_core::marker::PhantomData };
+    };
+    ($type:ty, $bound:ident) => {
+        $crate::_core::compile_error!($crate::_core::concat!(
+            "`const_assert_impl!` only supports `Send`, `Sync`, and `Unpin`, not `",
+            $crate::_core::stringify!($bound),
+            "`",
+        ));
+    };
+}
+
+/// Only well-formed, and so only nameable as the type of a `const`, when `T`
+/// is `Send`.
+#[doc(hidden)]
+pub struct _AssertSend<T: ?Sized + Send> {
+    pub _marker: PhantomData<fn() -> PhantomData<T>>,
+}
+
+#[doc(hidden)]
+pub struct _AssertSync<T: ?Sized + Sync> {
+    pub _marker: PhantomData<fn() -> PhantomData<T>>,
+}
+
+#[doc(hidden)]
+pub struct _AssertUnpin<T: ?Sized + Unpin> {
+    pub _marker: PhantomData<fn() -> PhantomData<T>>,
+}
@@ -0,0 +1,81 @@
+/// Asserts that constant strings are equal.
+///
+/// Strings can't be compared with `==` in constants, so this compares them
+/// byte by byte with [`str_eq`] instead. It requires Rust 1.46 or later.
+///
+/// # Examples
+///
+/// This is useful for ensuring that generated code stays in sync with the
+/// crate it was generated for:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// const VERSION: &str = "1.2.3";
+/// const GENERATED_FOR: &str = "1.2.3";
+///
+/// const_assert_eq_str!(VERSION, GENERATED_FOR);
+/// ```
+///
+/// Any number of strings can be compared against the first one:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// const_assert_eq_str!("", "", concat!(""));
+/// ```
+///
+/// The following example fails to compile because the strings differ in their
+/// last byte:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// const_assert_eq_str!("1.2.3", "1.2.4");
+/// ```
+///
+/// Strings of different lengths are never equal, even if one is a prefix of
+/// the other:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// const_assert_eq_str!("1.2", "1.2.3");
+/// ```
+///
+/// [`str_eq`]: fn.str_eq.html
+#[macro_export(local_inner_macros)]
+macro_rules! const_assert_eq_str {
+    ($x:expr, $($y:expr),+ $(,)?) => {
+        $(_const_assert!($crate::str_eq($x, $y));)+
+    };
+}
+
+/// Returns whether two strings are equal, within a `const` context.
+///
+/// Strings can't be compared with `==` in constants, so this compares them
+/// byte by byte instead. This requires Rust 1.46 or later, and is not defined
+/// on older compilers.
+///
+/// # Examples
+///
+/// ```
+/// use static_assertions::str_eq;
+///
+/// const SAME: bool = str_eq("1.2.3", "1.2.3");
+/// const PREFIX: bool = str_eq("1.2", "1.2.3");
+///
+/// assert!(SAME);
+/// assert!(!PREFIX);
+/// ```
+#[cfg(not(no_const_fn_loops))]
+pub const fn str_eq(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
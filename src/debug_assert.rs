@@ -0,0 +1,127 @@
+/// Asserts that constants are `true` when debug assertions are enabled.
+///
+/// This is [`const_assert!`] checked at runtime via [`debug_assert!`] rather
+/// than at compile-time. It is useful for assertions that only hold on
+/// certain targets, such as when cross-compiling a release build for a target
+/// that can't satisfy them, but that should still be checked by `cargo test`.
+///
+/// Unlike [`const_assert!`], this must be placed where statements are
+/// allowed, such as within a `#[test]` function. In return, it can refer to
+/// generic parameters.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {
+/// const WIDTH: usize = 640;
+///
+/// debug_const_assert!(WIDTH > 0);
+/// debug_const_assert!(WIDTH >= 320, WIDTH <= 1920);
+/// # }
+/// ```
+///
+/// The following example panics under `cargo test` and in other builds with
+/// debug assertions, but is not checked in release builds:
+///
+/// ```should_panic
+/// # #[macro_use] extern crate static_assertions; fn main() {
+/// # #[cfg(not(debug_assertions))] panic!();
+/// debug_const_assert!(5 * 5 == 5);
+/// # }
+/// ```
+///
+/// [`const_assert!`]: macro.const_assert.html
+/// [`debug_assert!`]: https://doc.rust-lang.org/std/macro.debug_assert.html
+#[macro_export]
+macro_rules! debug_const_assert {
+    ($($x:expr),+ $(,)?) => {
+        $($crate::_core::debug_assert!($x);)+
+    };
+}
+
+/// Asserts that types are equal in size when debug assertions are enabled.
+///
+/// This is [`assert_eq_size!`] checked at runtime via [`debug_assert_eq!`]
+/// rather than at compile-time. See [`debug_const_assert!`] for when this is
+/// useful and where it may be placed.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {
+/// debug_assert_eq_size!(u32, [u8; 4], (u16, u16));
+///
+/// fn check<T>() {
+///     debug_assert_eq_size!(T, u64);
+/// }
+///
+/// check::<[u8; 8]>();
+/// # }
+/// ```
+///
+/// The following example panics in builds with debug assertions because `u32`
+/// has 4 times the size of `u8`:
+///
+/// ```should_panic
+/// # #[macro_use] extern crate static_assertions; fn main() {
+/// # #[cfg(not(debug_assertions))] panic!();
+/// debug_assert_eq_size!(u32, u8);
+/// # }
+/// ```
+///
+/// [`assert_eq_size!`]: macro.assert_eq_size.html
+/// [`debug_assert_eq!`]: https://doc.rust-lang.org/std/macro.debug_assert_eq.html
+/// [`debug_const_assert!`]: macro.debug_const_assert.html
+#[macro_export]
+macro_rules! debug_assert_eq_size {
+    ($x:ty, $($xs:ty),+ $(,)?) => {
+        $($crate::_core::debug_assert_eq!(
+            $crate::_core::mem::size_of::<$x>(),
+            $crate::_core::mem::size_of::<$xs>(),
+            "`{}` and `{}` differ in size",
+            $crate::_core::stringify!($x),
+            $crate::_core::stringify!($xs),
+        );)+
+    };
+}
+
+/// Asserts that types are equal in alignment when debug assertions are
+/// enabled.
+///
+/// This is [`assert_eq_align!`] checked at runtime via [`debug_assert_eq!`]
+/// rather than at compile-time. See [`debug_const_assert!`] for when this is
+/// useful and where it may be placed.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {
+/// debug_assert_eq_align!([i32; 4], i32, u32);
+/// # }
+/// ```
+///
+/// The following example panics in builds with debug assertions because `u64`
+/// is more strictly aligned than `u8`:
+///
+/// ```should_panic
+/// # #[macro_use] extern crate static_assertions; fn main() {
+/// # #[cfg(not(debug_assertions))] panic!();
+/// debug_assert_eq_align!(u8, u64);
+/// # }
+/// ```
+///
+/// [`assert_eq_align!`]: macro.assert_eq_align.html
+/// [`debug_assert_eq!`]: https://doc.rust-lang.org/std/macro.debug_assert_eq.html
+/// [`debug_const_assert!`]: macro.debug_const_assert.html
+#[macro_export]
+macro_rules! debug_assert_eq_align {
+    ($x:ty, $($xs:ty),+ $(,)?) => {
+        $($crate::_core::debug_assert_eq!(
+            $crate::_core::mem::align_of::<$x>(),
+            $crate::_core::mem::align_of::<$xs>(),
+            "`{}` and `{}` differ in alignment",
+            $crate::_core::stringify!($x),
+            $crate::_core::stringify!($xs),
+        );)+
+    };
+}
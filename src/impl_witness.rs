@@ -0,0 +1,82 @@
+use core::fmt;
+use core::marker::PhantomData;
+
+/// A zero-sized witness that [`impl_witness!`] asserted traits on `T`.
+///
+/// A value of this type is only meant to be created by [`impl_witness!`], so
+/// storing or accepting one documents that the assertion was made. It only
+/// records the type, not which traits were asserted.
+///
+/// [`impl_witness!`]: macro.impl_witness.html
+pub struct ImplWitness<T: ?Sized> {
+    // Public for `impl_witness!`, which must be usable in a `const` context.
+    #[doc(hidden)]
+    pub _marker: PhantomData<fn() -> PhantomData<T>>,
+}
+
+impl<T: ?Sized> Clone for ImplWitness<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: ?Sized> Copy for ImplWitness<T> {}
+
+impl<T: ?Sized> fmt::Debug for ImplWitness<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("ImplWitness")
+    }
+}
+
+/// Asserts that the type implements _all_ of the given traits, and evaluates
+/// to an [`ImplWitness`] of it.
+///
+/// This performs the same check as [`assert_impl_all!`]. The resulting witness
+/// is zero-sized and can be kept alongside code that relies on the traits, such
+/// as in a `const`, to document that the check ran.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// use static_assertions::ImplWitness;
+///
+/// struct Config {
+///     name: &'static str,
+///     retries: u8,
+/// }
+///
+/// const CONFIG_IS_SHAREABLE: ImplWitness<Config> =
+///     impl_witness!(Config: Send, Sync);
+///
+/// fn share(
+///     config: &'static Config,
+///     _: ImplWitness<Config>,
+/// ) -> &'static Config {
+///     config
+/// }
+/// # static CONFIG: Config = Config { name: "", retries: 0 };
+/// # let _ = share(&CONFIG, CONFIG_IS_SHAREABLE);
+/// # let _ = (CONFIG.name, CONFIG.retries);
+/// ```
+///
+/// The following example fails to compile because [`Rc`] is not [`Send`]:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// use std::rc::Rc;
+///
+/// let witness = impl_witness!(Rc<u8>: Send);
+/// ```
+///
+/// [`assert_impl_all!`]: macro.assert_impl_all.html
+/// [`ImplWitness`]: struct.ImplWitness.html
+/// [`Rc`]: https://doc.rust-lang.org/std/rc/struct.Rc.html
+/// [`Send`]: https://doc.rust-lang.org/std/marker/trait.Send.html
+#[macro_export(local_inner_macros)]
+macro_rules! impl_witness {
+    ($type:ty: $($rest:tt)+) => {{
+        assert_impl_all!($type: $($rest)+);
+        $crate::ImplWitness::<$type> { _marker: $crate::_core::marker::PhantomData }
+    }};
+}
@@ -46,6 +46,14 @@
 //! sa::const_assert!(true);
 //! ```
 //!
+//! Alternatively, all assertions can be imported at once from the [`prelude`]:
+//!
+//! ```edition2018
+//! use static_assertions::prelude::*;
+//!
+//! const_assert!(true);
+//! ```
+//!
 //! ## Procedural Extensions
 //!
 //! As an extension crate [`proc_static_assertions`] adds a number of new
@@ -60,6 +68,36 @@
 //! static_assertions = { version = "1.1.0", features = ["proc"] }
 //! ```
 //!
+//! ## Runtime Assertions
+//!
+//! Some assertions can only hold on certain targets, such as the size of a
+//! type when cross-compiling. The following check the same conditions as their
+//! compile-time counterparts with [`debug_assert!`]s instead:
+//!
+//! - [`debug_const_assert!`] for [`const_assert!`]
+//! - [`debug_assert_eq_size!`] for [`assert_eq_size!`]
+//! - [`debug_assert_eq_align!`] for [`assert_eq_align!`]
+//!
+//! The `runtime` feature flag turns the compile-time counterparts, as well as
+//! the other `const_assert_*` macros, into these:
+//!
+//! ```toml
+//! [dev-dependencies]
+//! static_assertions = { version = "1.1.0", features = ["runtime"] }
+//! ```
+//!
+//! This changes their semantics considerably:
+//!
+//! - A failed assertion panics when the call is reached under `cargo test` or
+//!   in other builds with debug assertions, rather than failing to build.
+//! - The assertion is not checked at all in release builds.
+//! - The macros can only be used where statements are allowed, such as within
+//!   a `#[test]` function. Uses at the module level fail to compile.
+//!
+//! Because features are unified across a dependency graph, the feature should
+//! only be enabled by the final binary or test crate. All other assertions,
+//! such as those over trait implementations, remain compile-time only.
+//!
 //! # Examples
 //!
 //! Very thorough examples are provided in the docs for
@@ -84,6 +122,14 @@
 //!     <img src="https://buymecoffee.intm.org/img/button-paypal-white.png" alt="Buy me a coffee" height="35">
 //! </a>
 //!
+//! [`prelude`]: prelude/index.html
+//! [`const_assert!`]: macro.const_assert.html
+//! [`assert_eq_size!`]: macro.assert_eq_size.html
+//! [`assert_eq_align!`]: macro.assert_eq_align.html
+//! [`debug_const_assert!`]: macro.debug_const_assert.html
+//! [`debug_assert_eq_size!`]: macro.debug_assert_eq_size.html
+//! [`debug_assert_eq_align!`]: macro.debug_assert_eq_align.html
+//! [`debug_assert!`]: https://doc.rust-lang.org/std/macro.debug_assert.html
 //! [`proc_static_assertions`]: https://docs.rs/proc_static_assertions
 //! [procedural macros]: https://doc.rust-lang.org/book/ch19-06-macros.html#procedural-macros-for-generating-code-from-attributes
 //! [Rust 1.37]: https://blog.rust-lang.org/2019/08/15/Rust-1.37.0.html
@@ -96,6 +142,7 @@
 #![doc(html_logo_url = "https://raw.githubusercontent.com/nvzqz/static-assertions-rs/assets/Icon.png")]
 
 #![no_std]
+#![cfg_attr(feature = "nightly", feature(variant_count))]
 
 #![deny(unused_macros)]
 
@@ -107,12 +154,49 @@ pub use proc_static_assertions::assert;
 #[doc(hidden)]
 pub extern crate core as _core;
 
+mod assert_align;
+mod assert_array;
+mod assert_assoc_const;
+mod assert_assoc_impl;
 mod assert_cfg;
+mod assert_const_fn;
+mod assert_copy;
+mod assert_default;
+mod assert_drop;
+mod assert_enum;
 mod assert_eq_align;
+mod assert_eq_hash;
 mod assert_eq_size;
 mod assert_fields;
+mod assert_generic_fn;
 mod assert_impl;
+mod assert_layout;
 mod assert_obj_safe;
+mod assert_send_sync;
+mod assert_size;
 mod assert_trait;
+mod assert_transmute;
 mod assert_type;
+mod assert_unpin;
+mod assert_utf8;
+mod assert_variance;
 mod const_assert;
+mod const_assert_impl;
+mod const_str;
+mod debug_assert;
+mod impl_witness;
+
+pub mod prelude;
+
+pub use impl_witness::ImplWitness;
+
+#[cfg(not(no_const_fn_loops))]
+pub use const_str::str_eq;
+
+#[doc(hidden)]
+pub use assert_type::_FnOutput;
+#[doc(hidden)]
+pub use const_assert_impl::{_AssertSend, _AssertSync, _AssertUnpin};
+#[doc(hidden)]
+#[cfg(feature = "nightly")]
+pub use assert_enum::_variant_count;
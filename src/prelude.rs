@@ -0,0 +1,42 @@
+//! Re-exports of every assertion macro in this crate.
+//!
+//! When using [Rust 2018 edition][2018] or later, this allows for importing all
+//! assertions at once instead of using `#[macro_use]`:
+//!
+//! ```edition2018
+//! use static_assertions::prelude::*;
+//!
+//! const_assert!(true);
+//! assert_eq_size!(u32, [u8; 4]);
+//! assert_impl_all!(u8: Send, Sync);
+//! ```
+//!
+//! The `assert` attribute from the `proc` feature is not included, since a glob
+//! import of it would shadow the standard [`assert!`] macro.
+//!
+//! [2018]: https://blog.rust-lang.org/2018/12/06/Rust-1.31-and-rust-2018.html#rust-2018
+//! [`assert!`]: https://doc.rust-lang.org/std/macro.assert.html
+
+pub use crate::{
+    assert_align_ge, assert_align_gt, assert_align_le, assert_align_lt,
+    assert_array_len, assert_assoc_const, assert_assoc_impl, assert_cfg,
+    assert_clone_not_copy, assert_const_fn, assert_copy, assert_covariant,
+    assert_default_eq, assert_discriminants, assert_enum_variants,
+    assert_eq_align, assert_eq_hash, assert_eq_size, assert_eq_size_ptr,
+    assert_eq_size_val, assert_feature_available, assert_fields,
+    assert_fn_ptr_compatible, assert_generic_fn, assert_impl_all,
+    assert_impl_all_cfg, assert_impl_any, assert_impl_one, assert_impl_ref,
+    assert_impl_val, assert_iter_item, assert_layout_eq, assert_no_drop,
+    assert_no_drop_impl, assert_no_padding, assert_non_zero_sized,
+    assert_not_impl_all, assert_not_impl_any, assert_not_unpin, assert_obj_safe,
+    assert_pointer_width, assert_repr_c_layout, assert_return_type_eq,
+    assert_send_sync, assert_size, assert_size_ge, assert_size_gt,
+    assert_size_le, assert_size_lt, assert_trait_method, assert_trait_sub_all,
+    assert_trait_super_all, assert_transmute_safe, assert_type_eq_all,
+    assert_type_ne_all, assert_unpin, assert_upcast, assert_valid_utf8,
+    assert_variant_count, assert_zero_sized, const_assert, const_assert_all,
+    const_assert_any, const_assert_eq, const_assert_eq_str, const_assert_ge,
+    const_assert_gt, const_assert_impl, const_assert_le, const_assert_lt,
+    const_assert_ne, debug_assert_eq_align, debug_assert_eq_size,
+    debug_const_assert, impl_witness, test_assert_impl_all,
+};
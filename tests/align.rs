@@ -0,0 +1,29 @@
+#![no_std]
+#![deny(unsafe_code)]
+
+#[macro_use]
+extern crate static_assertions;
+
+#[allow(dead_code)]
+#[repr(align(8))]
+struct Aligned8(u8);
+
+#[allow(dead_code)]
+#[repr(align(16))]
+struct Aligned16(u8);
+
+assert_align_le!(Aligned8, 8);
+assert_align_le!(u8, 1, "bytes are not aligned");
+assert_align_le!((), 1,);
+
+assert_align_lt!(Aligned8, 16);
+assert_align_lt!(u8, 2);
+assert_align_lt!(u8, 4, "alignment must be {small}");
+
+assert_align_ge!(Aligned16, 16);
+assert_align_ge!([Aligned16; 0], 16, "arrays keep the element alignment");
+assert_align_ge!(u8, 1);
+
+assert_align_gt!(Aligned16, 8);
+assert_align_gt!(Aligned8, 1,);
+assert_align_gt!(Aligned16, 1, "{} must be {}");
@@ -0,0 +1,27 @@
+#![no_std]
+#![deny(unsafe_code)]
+
+#[macro_use]
+extern crate static_assertions;
+
+const TABLE: [u8; 256] = [0; 256];
+const NAMES: &[&str] = &["red", "green", "blue"];
+const EMPTY: [u32; 0] = [];
+const ENTRIES: usize = 4;
+
+static LOOKUP: [u16; ENTRIES] = [1, 2, 4, 8];
+
+assert_array_len!(TABLE, 256);
+assert_array_len!(TABLE, u8::MAX as usize + 1,);
+assert_array_len!(NAMES, 3);
+assert_array_len!(EMPTY, 0);
+assert_array_len!([0u8; ENTRIES], ENTRIES, "array literals work too");
+assert_array_len!(NAMES, 3, "names are {red, green, blue}");
+assert_array_len!(LOOKUP, ENTRIES, "statics work on newer compilers");
+assert_array_len!(b"abc", 3);
+
+mod tables {
+    pub const SQUARES: [u32; 4] = [0, 1, 4, 9];
+}
+
+assert_array_len!(tables::SQUARES, 4);
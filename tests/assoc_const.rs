@@ -0,0 +1,37 @@
+#![no_std]
+#![deny(unsafe_code)]
+
+#[macro_use]
+extern crate static_assertions;
+
+trait Id {
+    const VALUE: u32;
+}
+
+trait Codec<T> {
+    const SIZE: usize;
+    const NAME: &'static str;
+}
+
+#[allow(dead_code)]
+struct Foo;
+
+impl Id for Foo {
+    const VALUE: u32 = 7;
+}
+
+impl Codec<u64> for Foo {
+    const SIZE: usize = 10;
+    const NAME: &'static str = "varint";
+}
+
+assert_assoc_const!(Foo: Id, VALUE == 7);
+assert_assoc_const!(Foo: Id, VALUE > 0 && Foo::VALUE < 8);
+assert_assoc_const!(Foo: self::Id, VALUE.count_ones() == 3);
+assert_assoc_const!(Foo: Codec<u64>, SIZE <= 10);
+assert_assoc_const!(Foo: Codec<u64>, NAME.len() == 6);
+assert_assoc_const!(u8: Id, VALUE == 1);
+
+impl Id for u8 {
+    const VALUE: u32 = 1;
+}
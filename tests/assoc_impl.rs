@@ -0,0 +1,32 @@
+#![no_std]
+#![deny(unsafe_code)]
+
+#[macro_use]
+extern crate static_assertions;
+
+extern crate alloc;
+
+use alloc::rc::Rc;
+use alloc::string::String;
+use alloc::vec::{IntoIter, Vec};
+use core::ops::Add;
+
+trait Stream {
+    type Item;
+    type Error;
+}
+
+#[allow(dead_code)]
+struct Frames;
+
+impl Stream for Frames {
+    type Item = Vec<u8>;
+    type Error = Rc<str>;
+}
+
+assert_assoc_impl!(IntoIter<String>: Iterator, Item: Send, Sync, Clone);
+assert_assoc_impl!(Vec<u8>: IntoIterator, IntoIter: Send, Iterator<Item = u8>,);
+assert_assoc_impl!(u32: Add<u32>, Output: Copy + Ord);
+assert_assoc_impl!(Frames: Stream, Item: Send; "frames are sent to the decoder");
+assert_assoc_impl!(Frames: Stream, Error: Clone, !Send);
+assert_not_impl_any!(<IntoIter<Rc<u8>> as Iterator>::Item: Send);
@@ -0,0 +1,43 @@
+//! Custom `auto` traits and negative implementations, which are unstable.
+
+#![cfg(feature = "nightly")]
+#![feature(auto_traits, negative_impls)]
+#![no_std]
+#![deny(unsafe_code)]
+
+#[macro_use]
+extern crate static_assertions;
+
+extern crate alloc;
+
+#[path = "auto_trait/plain.rs"]
+mod plain;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use plain::{Handle, Plain};
+
+#[allow(dead_code)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[allow(dead_code)]
+struct Wrapper<T>(T);
+
+assert_impl_all!(Point: Plain, Send, Sync);
+assert_impl_all!([u8, String, Vec<Point>, (Point, char)]: Plain);
+assert_impl_all!(Wrapper<Point>: Plain; "wrappers of plain types are plain");
+assert_impl_all!(for<T: Plain> Wrapper<T>: Plain);
+assert_impl_all!(fn() -> Handle: Plain);
+
+// Negative implementations are inherited by types containing them, including
+// through `PhantomData`.
+assert_not_impl_any!(Handle: Plain);
+assert_not_impl_any!(Wrapper<Handle>: Plain);
+assert_not_impl_any!((u8, Handle): Plain);
+assert_not_impl_any!(PhantomData<Handle>: Plain);
+assert_impl_all!(Handle: Send, !Plain);
+assert_not_impl_all!(Handle: Send, Plain);
@@ -0,0 +1,10 @@
+//! A custom `auto` trait with a negative implementation. Their syntax is
+//! unstable even within items removed by `#[cfg]`, so this module is only
+//! loaded with the `nightly` feature flag.
+
+pub auto trait Plain {}
+
+#[allow(dead_code)]
+pub struct Handle(pub u32);
+
+impl !Plain for Handle {}
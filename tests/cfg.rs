@@ -0,0 +1,11 @@
+#![no_std]
+#![deny(unsafe_code)]
+
+#[macro_use]
+extern crate static_assertions;
+
+assert_cfg!(all());
+assert_cfg!(any(unix, not(unix)));
+assert_cfg!(not(any()), "Empty `any` is always false");
+assert_cfg!("Empty `all` is always true", all());
+assert_cfg!(any(target_os = "linux", not(target_os = "linux")),);
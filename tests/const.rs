@@ -5,11 +5,81 @@
 extern crate static_assertions;
 
 const_assert!(true && (true != false));
-const_assert!((true && true) != false);
+#[allow(clippy::bool_comparison)]
+const _: () = {
+    const_assert!((true && true) != false);
+};
 const_assert_eq!(false, false);
+const_assert_eq!(1 + 1, 2, 4 / 2, 1 << 1,);
+const_assert_ne!(1, 2);
+const_assert_ne!(0, 1, 2, 3);
 
 #[allow(dead_code)]
 const FIVE: usize = 5;
 
 const_assert!(FIVE * 2 == 10);
 const_assert!(FIVE > 2);
+
+#[allow(dead_code)]
+const SIZE: usize = 1 << 4;
+
+const_assert!(SIZE > 0 && SIZE.is_power_of_two());
+const_assert!(SIZE % FIVE == 1);
+const_assert!(!0u8 as usize + 1 == 256);
+
+#[test]
+fn const_assert_in_fn() {
+    const_assert!(SIZE - FIVE * 3 == 1);
+}
+
+#[allow(dead_code)]
+struct Bounds;
+
+#[allow(dead_code)]
+impl Bounds {
+    const MIN: i32 = -8;
+    const MAX: i32 = 8;
+}
+
+const_assert_lt!(FIVE, SIZE);
+const_assert_lt!(Bounds::MIN, Bounds::MAX);
+const_assert_le!(FIVE, FIVE);
+const_assert_le!(Bounds::MIN, Bounds::MAX,);
+const_assert_gt!(SIZE, FIVE);
+const_assert_gt!(0, Bounds::MIN);
+const_assert_ge!(FIVE, FIVE);
+const_assert_ge!(Bounds::MAX, Bounds::MIN,);
+
+const USE_TCP: bool = false;
+const USE_UDP: bool = true;
+
+const_assert_all!(true);
+const_assert_all!(USE_UDP, !USE_TCP, 1 + 1 == 2,);
+
+const_assert_any!(true);
+const_assert_any!(USE_TCP, USE_UDP);
+const_assert_any!(false, USE_TCP, 2 > 1,);
+
+// Generic parameters are only in scope for inline `const` blocks.
+#[allow(dead_code)]
+struct RingBuffer<T, const N: usize> {
+    items: [Option<T>; N],
+}
+
+impl<T, const N: usize> RingBuffer<T, N> {
+    const MASK: usize = N.wrapping_sub(1);
+
+    fn new() -> Self {
+        const_assert!(const { N >= 1 });
+        const_assert!(const { N.is_power_of_two() },);
+        const_assert!(const { core::mem::size_of::<T>() <= 16 && Self::MASK < N });
+        RingBuffer { items: [(); N].map(|_| None) }
+    }
+}
+
+#[test]
+fn const_assert_generic() {
+    let buffer = RingBuffer::<u64, 4>::new();
+    assert_eq!(buffer.items.len(), 4);
+    let _ = RingBuffer::<(), 1>::new();
+}
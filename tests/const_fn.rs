@@ -0,0 +1,37 @@
+#![no_std]
+#![deny(unsafe_code)]
+
+#[macro_use]
+extern crate static_assertions;
+
+extern crate alloc;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+const fn align_up(value: usize, align: usize) -> usize {
+    (value + align - 1) & !(align - 1)
+}
+
+#[allow(dead_code)]
+struct Config {
+    retries: u8,
+}
+
+impl Config {
+    const fn new() -> Self {
+        Config { retries: 3 }
+    }
+}
+
+const ALIGN: usize = 16;
+
+assert_const_fn!(align_up(3, 8));
+assert_const_fn!(align_up(ALIGN, ALIGN), Config::new(), u8::MAX,);
+assert_const_fn!(String::new(), Vec::<u8>::new(), core::mem::size_of::<u64>());
+
+#[test]
+fn test_const_fn() {
+    assert_const_fn!(align_up(1, 4));
+    assert_eq!(align_up(5, 4), 8);
+}
@@ -0,0 +1,36 @@
+#![no_std]
+#![deny(unsafe_code)]
+
+#[macro_use]
+extern crate static_assertions;
+
+extern crate alloc;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::cell::Cell;
+use core::marker::PhantomPinned;
+
+#[allow(dead_code)]
+struct Config {
+    name: &'static str,
+    retries: u8,
+}
+
+static CONFIG: Config = Config { name: "default", retries: 3 };
+
+const_assert_impl!(Config: Send, Sync, Unpin);
+const_assert_impl!(String: Send);
+const_assert_impl!(Vec<Config>: Sync, Send,);
+const_assert_impl!(str: Send, Sync);
+const_assert_impl!(Cell<u8>: Send, Unpin);
+const_assert_impl!(dyn Send: Send);
+
+assert_not_impl_any!(Cell<u8>: Sync);
+assert_not_impl_any!(PhantomPinned: Unpin);
+
+#[test]
+fn test_const_impl() {
+    const_assert_impl!(&'static Config: Send, Sync);
+    assert_eq!(CONFIG.retries, 3);
+}
@@ -0,0 +1,21 @@
+#![no_std]
+#![deny(unsafe_code)]
+
+#[macro_use]
+extern crate static_assertions;
+
+const VERSION: &str = "1.2.3";
+
+const_assert_eq_str!(VERSION, "1.2.3");
+const_assert_eq_str!("", "");
+const_assert_eq_str!("Grüße", "Grüße", "Gr\u{fc}\u{df}e",);
+const_assert_eq_str!(concat!("1.", "2.", "3"), VERSION);
+
+mod inner {
+    const_assert_eq_str!(super::VERSION, "1.2.3");
+}
+
+const_assert!(static_assertions::str_eq("", ""));
+const_assert!(!static_assertions::str_eq("", "1"));
+const_assert!(!static_assertions::str_eq("1.2", "1.2.3"));
+const_assert!(!static_assertions::str_eq("1.2.3", "1.2.4"));
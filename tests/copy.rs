@@ -0,0 +1,29 @@
+#![no_std]
+#![deny(unsafe_code)]
+
+#[macro_use]
+extern crate static_assertions;
+
+extern crate alloc;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+#[allow(dead_code)]
+#[derive(Clone, Copy)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[allow(dead_code)]
+#[derive(Clone)]
+struct Buffer {
+    data: Vec<u8>,
+}
+
+assert_copy!(u32);
+assert_copy!(Point, &'static str, (u8, char), [Point; 4], Option<Point>,);
+
+assert_clone_not_copy!(String);
+assert_clone_not_copy!(Buffer, Vec<u8>, (Point, String), Option<Buffer>,);
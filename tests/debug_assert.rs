@@ -0,0 +1,45 @@
+#![no_std]
+#![deny(unsafe_code)]
+
+#[macro_use]
+extern crate static_assertions;
+
+#[test]
+fn test_passing() {
+    const WIDTH: usize = 640;
+
+    debug_const_assert!(WIDTH > 0);
+    debug_const_assert!(WIDTH >= 320, WIDTH <= 1920,);
+    debug_assert_eq_size!(u32, [u8; 4], (u16, u16));
+    debug_assert_eq_align!([i32; 4], i32);
+}
+
+#[test]
+fn test_generic() {
+    // Unlike at compile time, generic parameters can be referred to.
+    fn check<T>() {
+        debug_const_assert!(core::mem::size_of::<T>() <= 8);
+        debug_assert_eq_size!(T, u64);
+    }
+
+    check::<u64>();
+    check::<[u8; 8]>();
+}
+
+#[test]
+#[cfg_attr(debug_assertions, should_panic(expected = "assertion failed"))]
+fn test_const_assert_false() {
+    debug_const_assert!(5 * 5 == 5);
+}
+
+#[test]
+#[cfg_attr(debug_assertions, should_panic(expected = "differ in size"))]
+fn test_eq_size_mismatch() {
+    debug_assert_eq_size!(u32, u8);
+}
+
+#[test]
+#[cfg_attr(debug_assertions, should_panic(expected = "differ in alignment"))]
+fn test_eq_align_mismatch() {
+    debug_assert_eq_align!(u8, u64);
+}
@@ -0,0 +1,26 @@
+//! Defaults compared in a `const`, which needs unstable `const` traits.
+
+#![cfg(feature = "nightly")]
+#![feature(const_trait_impl, const_default, const_cmp)]
+#![no_std]
+#![deny(unsafe_code)]
+
+#[macro_use]
+extern crate static_assertions;
+
+#[path = "default_eq/config.rs"]
+mod config;
+
+use config::{Config, DEFAULT_CONFIG};
+
+assert_default_eq!(Config, DEFAULT_CONFIG);
+assert_default_eq!(Config, Config { retries: 3, verbose: false },);
+assert_default_eq!(u32, 0);
+assert_default_eq!(bool, false);
+assert_default_eq!(Option<char>, None);
+
+#[test]
+fn test_default_eq() {
+    assert_default_eq!(Config, DEFAULT_CONFIG);
+    assert_default_eq!(usize, 0);
+}
@@ -0,0 +1,22 @@
+//! A type with `const` trait implementations. Their syntax is rejected even
+//! within items removed by `#[cfg]`, so this module is only loaded with the
+//! `nightly` feature flag.
+
+pub struct Config {
+    pub retries: u32,
+    pub verbose: bool,
+}
+
+impl const Default for Config {
+    fn default() -> Self {
+        Config { retries: 3, verbose: false }
+    }
+}
+
+impl const PartialEq for Config {
+    fn eq(&self, other: &Self) -> bool {
+        self.retries == other.retries && self.verbose == other.verbose
+    }
+}
+
+pub const DEFAULT_CONFIG: Config = Config { retries: 3, verbose: false };
@@ -0,0 +1,58 @@
+#![no_std]
+#![deny(unsafe_code)]
+
+#[macro_use]
+extern crate static_assertions;
+
+extern crate alloc;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+#[allow(dead_code)]
+#[derive(Clone, Copy)]
+struct Pod {
+    id: u32,
+    flags: u8,
+}
+
+#[allow(dead_code)]
+struct Tag;
+
+// Types without `Drop` glue need not be `Copy`.
+#[allow(dead_code)]
+struct NotCopy([Pod; 4]);
+
+assert_no_drop!(Pod);
+assert_no_drop!(Tag, (), PhantomData<Tag>, NotCopy,);
+assert_no_drop!(&'static mut NotCopy, [Pod; 0], (u8, Tag));
+assert_no_drop!(Pod, Tag; "plain data is never dropped");
+assert_no_drop!(Pod; "`{}` must stay plain data");
+
+// Fields that need to be dropped do not imply a `Drop` impl.
+#[allow(dead_code)]
+struct Named {
+    id: u32,
+    name: String,
+}
+
+#[allow(dead_code)]
+struct Guard(Pod);
+
+impl Drop for Guard {
+    fn drop(&mut self) {}
+}
+
+#[allow(dead_code)]
+struct Wrapper(Guard);
+
+assert_no_drop_impl!(Pod);
+assert_no_drop_impl!(Named, String, Wrapper, Option<Guard>,);
+#[allow(drop_bounds)]
+const _: () = {
+    assert_impl_all!(Guard: Drop, Sized);
+    assert_impl_all!(Vec<u8>: Drop);
+};
+const_assert!(core::mem::needs_drop::<Named>());
+const_assert!(core::mem::needs_drop::<Wrapper>());
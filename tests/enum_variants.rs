@@ -0,0 +1,72 @@
+#![no_std]
+#![deny(unsafe_code)]
+
+#[macro_use]
+extern crate static_assertions;
+
+#[allow(dead_code)]
+enum Color {
+    Red,
+    Green,
+    Blue,
+}
+
+assert_enum_variants!(Color: Red, Green, Blue);
+assert_enum_variants!(Color: Blue, Red, Green,);
+
+assert_variant_count!(Color, 3; Red, Green, Blue);
+assert_variant_count!(Color, 1 + 2; Blue, Green, Red,);
+
+#[allow(dead_code)]
+enum Message {
+    Quit,
+    Move { x: i32, y: i32 },
+    Write(&'static str),
+}
+
+assert_enum_variants!(Message: Quit, Move { .. }, Write(..));
+assert_enum_variants!(Message: Write, Move, Quit);
+assert_variant_count!(Message, 3; Quit, Move { .. }, Write(..));
+
+mod inner {
+    #[allow(dead_code)]
+    pub enum Either<L, R> {
+        Left(L),
+        Right(R),
+    }
+}
+
+assert_enum_variants!(inner::Either<u8, u16>: Left, Right);
+assert_enum_variants!(core::cmp::Ordering: Less, Equal, Greater);
+assert_enum_variants!(Option<u8>: Some(..), None);
+
+#[allow(dead_code)]
+#[repr(u8)]
+enum Status {
+    Ok = 0,
+    Err = 1,
+}
+
+assert_discriminants!(Status: Ok = 0, Err = 1);
+assert_discriminants!(Status: Err = 1,);
+
+#[allow(dead_code)]
+#[repr(i16)]
+enum Code {
+    Invalid = -1,
+    Start = 10,
+    Next,
+    End = 0x100,
+}
+
+assert_discriminants!(Code: Invalid = -1, Start = 10, Next = 11, End = 0x100);
+assert_discriminants!(Code: End = 1 << 8, Start = 5 * 2);
+
+#[allow(dead_code)]
+#[repr(u64)]
+enum Big {
+    Max = 0xFFFF_FFFF_FFFF_FFFF,
+}
+
+assert_discriminants!(Big: Max = 0xFFFF_FFFF_FFFF_FFFF);
+assert_discriminants!(core::cmp::Ordering: Less = -1, Equal = 0, Greater = 1);
@@ -0,0 +1,25 @@
+#![no_std]
+#![deny(unsafe_code)]
+
+#[macro_use]
+extern crate static_assertions;
+
+use core::marker::PhantomData;
+
+assert_eq_align!(u64, f64);
+assert_eq_align!(u8, i8, bool, [u8; 3], (u8, u8),);
+
+// Zero-sized types still have an alignment.
+assert_eq_align!((), PhantomData<u64>, u8);
+assert_eq_align!([u64; 0], u64);
+
+// Alignment can be greater than size.
+#[allow(dead_code)]
+#[repr(align(16))]
+struct Aligned(u8);
+
+#[allow(dead_code)]
+#[repr(align(16))]
+struct AlignedZst;
+
+assert_eq_align!(Aligned, AlignedZst, [Aligned; 4]);
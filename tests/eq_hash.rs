@@ -0,0 +1,32 @@
+#![no_std]
+#![deny(unsafe_code)]
+
+#[macro_use]
+extern crate static_assertions;
+
+extern crate alloc;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+#[allow(dead_code)]
+#[derive(PartialEq, Eq, Hash)]
+struct UserId(u64);
+
+#[allow(dead_code)]
+#[derive(PartialEq, Eq, Hash)]
+enum Key {
+    Id(UserId),
+    Name(String),
+}
+
+assert_eq_hash!(UserId);
+assert_eq_hash!(Key, String, Vec<u8>, (u8, char), [UserId; 2], Option<Key>,);
+assert_eq_hash!(&'static str, core::cmp::Reverse<u32>);
+
+#[allow(dead_code)]
+#[derive(PartialEq, Eq)]
+struct Unhashed(u32);
+
+assert_not_impl_any!(Unhashed: core::hash::Hash);
+assert_not_impl_any!(f64: Eq);
@@ -5,6 +5,7 @@
 extern crate static_assertions;
 
 assert_eq_size!(u8, u8, (u8,), [u8; 1]);
+assert_eq_size!(u32, [u8; 4], (u16, u16));
 
 mod assoc_type {
     trait Trait {
@@ -78,11 +79,20 @@ fn test_eq_size() {
         assert_eq_size_val!(0u8, 0u8);
     }
 
+    let a = 1u32;
+    let b = [0u8; 4];
+    assert_eq_size_val!(a, b);
+
     let x = &mut 0;
     assert_eq_size_ptr!(x, &0);
     *x = 20;
     assert_eq_size_ptr!(x, &0);
 
+    // Null pointers are fine since they are never read from.
+    let p = core::ptr::null::<u64>();
+    let q = core::ptr::null_mut::<[u8; 8]>();
+    assert_eq_size_ptr!(p, q, &0.0f64);
+
     // Should fail to compile (un-comment to test manually):
     // assert_eq_size!(u8, u16);
     // assert_eq_size_val!(0u8, 0u16);
@@ -0,0 +1,37 @@
+#![no_std]
+#![deny(unsafe_code)]
+
+#[macro_use]
+extern crate static_assertions;
+
+use core::error::Error;
+use core::fmt;
+
+#[allow(dead_code)]
+#[derive(Debug)]
+enum ParseError {
+    Empty,
+    Invalid(u8),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseError::Empty => f.write_str("empty input"),
+            ParseError::Invalid(byte) => write!(f, "invalid byte {}", byte),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+#[allow(dead_code)]
+#[derive(Debug)]
+struct Opaque;
+
+assert_impl_all!(ParseError: Error, fmt::Debug, fmt::Display, Send, Sync);
+assert_impl_all!(ParseError: core::error::Error; "errors must work without std");
+assert_impl_all!(&'static ParseError: Error);
+assert_impl_all!(dyn Error + Send + Sync: Error, Send, Sync);
+assert_impl_all!(core::fmt::Error: Error);
+assert_not_impl_any!(Opaque: Error, fmt::Display);
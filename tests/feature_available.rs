@@ -0,0 +1,17 @@
+//! Requires `--cfg 'feature_available="const_str_eq"'`, as a build script
+//! would set after detecting the capability.
+
+#![cfg(feature_available = "const_str_eq")]
+#![no_std]
+#![deny(unsafe_code)]
+
+#[macro_use]
+extern crate static_assertions;
+
+assert_feature_available!("const_str_eq");
+assert_feature_available!("const_str_eq", "const_str_eq",);
+
+#[test]
+fn test_feature_available() {
+    assert_feature_available!("const_str_eq");
+}
@@ -14,8 +14,13 @@ assert_fields!(Foo::A: x);
 assert_fields!(Foo::A: x, x);
 assert_fields!(Foo::A: x, y, x);
 
-// TODO: Make tuple field access possible
-// assert_fields!(Foo::B, 0);
+assert_fields!(Foo::B: 0);
+
+#[allow(dead_code)]
+struct Rgba(u8, u8, u8, u8);
+
+assert_fields!(Rgba: 0, 1, 2, 3);
+assert_fields!(Rgba: 3, 0,);
 
 mod m {
     #[allow(dead_code)]
@@ -27,3 +32,13 @@ use m::Bar as Baz;
 
 assert_fields!(m::Bar<str>: inner, nul);
 assert_fields!(Baz<dyn Send>: inner, nul);
+
+assert_fields!(core::ops::Range<u8>: start, end);
+
+mod n {
+    #[allow(dead_code)]
+    pub struct Qux { pub a: u8, b: u8 }
+}
+
+assert_fields!(n::Qux: a);
+assert_fields!(Foo::A: y,);
@@ -0,0 +1,41 @@
+#![no_std]
+#![deny(unsafe_code)]
+
+#[macro_use]
+extern crate static_assertions;
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+fn dup<T: Clone>(x: &T) -> (T, T) {
+    (x.clone(), x.clone())
+}
+
+fn collect<I: IntoIterator>(iter: I) -> Vec<I::Item> {
+    iter.into_iter().collect()
+}
+
+assert_generic_fn!(fn<T: Clone>() {
+    let _ = dup::<T>;
+});
+
+assert_generic_fn!(fn<T>(x: &T) where T: Clone + Default {
+    let (_, _) = dup(x);
+    let _ = T::default();
+});
+
+assert_generic_fn!(fn<'a, T: 'a + Clone, I: Iterator<Item = &'a T>>(iter: I) -> Vec<T> {
+    collect(iter.cloned())
+});
+
+assert_generic_fn!(fn<T: Clone, const N: usize>(x: [T; N]) {
+    let _ = dup(&x);
+});
+
+#[test]
+fn test_generic_fn() {
+    assert_generic_fn!(fn<T: PartialOrd>(a: T, b: T) -> bool {
+        a < b
+    });
+}
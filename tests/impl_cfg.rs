@@ -0,0 +1,27 @@
+#![no_std]
+#![deny(unsafe_code)]
+
+#[macro_use]
+extern crate static_assertions;
+
+extern crate std;
+
+use std::fs::File;
+
+// Active on the respective hosts.
+assert_impl_all_cfg!(unix, File: std::os::unix::fs::FileExt, Send);
+assert_impl_all_cfg!(windows, File: std::os::windows::fs::FileExt, Send);
+assert_impl_all_cfg!(any(unix, windows), File: std::io::Read, std::io::Write,);
+assert_impl_all_cfg!(all(), [File, std::net::TcpStream]: std::io::Read; "streams are readable");
+assert_impl_all_cfg!(not(any()), for<T: Send> std::vec::Vec<T>: Send);
+
+// Absent, since the paths to the traits do not exist.
+assert_impl_all_cfg!(target_os = "none", File: std::os::none::FileExt);
+assert_impl_all_cfg!(any(), u8: missing::Trait);
+assert_impl_all_cfg!(all(unix, windows), File: Unsatisfiable);
+
+#[test]
+fn test_impl_all_cfg() {
+    assert_impl_all_cfg!(any(unix, windows), File: std::io::Seek);
+    assert_impl_all_cfg!(any(), File: missing::Trait);
+}
@@ -0,0 +1,37 @@
+#![no_std]
+#![deny(unsafe_code)]
+
+#[macro_use]
+extern crate static_assertions;
+
+use core::mem;
+use static_assertions::ImplWitness;
+
+#[allow(dead_code)]
+struct Config {
+    retries: u8,
+}
+
+const CONFIG: ImplWitness<Config> = impl_witness!(Config: Send, Sync);
+const STR: ImplWitness<str> = impl_witness!(str: Send, AsRef<[u8]>,);
+
+#[allow(dead_code)]
+struct Shared {
+    config: Config,
+    witness: ImplWitness<Config>,
+}
+
+assert_zero_sized!(ImplWitness<Config>, ImplWitness<str>);
+assert_eq_size!(Shared, Config);
+assert_impl_all!(ImplWitness<*const u8>: Send, Sync, Copy, core::fmt::Debug);
+
+#[test]
+fn test_impl_witness() {
+    let witness = impl_witness!(u8: Copy; "bytes are copied");
+    let copy = witness;
+    let _ = (witness, copy, CONFIG, STR);
+    assert_eq!(mem::size_of_val(&witness), 0);
+
+    let shared = Shared { config: Config { retries: 3 }, witness: CONFIG };
+    assert_eq!(shared.config.retries, 3);
+}
@@ -0,0 +1,53 @@
+#![no_std]
+#![deny(unsafe_code)]
+
+#[macro_use]
+extern crate static_assertions;
+
+// Labels from before `const _` are still accepted, even when reused.
+const_assert!(label; true);
+const_assert!(label; 1 + 1 == 2);
+const_assert_eq!(label; 2 * 2, 2 + 2);
+const_assert_ne!(label; 2, 3);
+
+assert_eq_size!(label; u32, [u8; 4]);
+
+assert_impl_all!(label; u8: Send, Sync);
+assert_impl_all!(label; u16: Send, Sync);
+assert_impl_all!(label; for<T: Send> [T; 2]: Send);
+assert_not_impl_all!(label; *const u8: Send, Sync);
+assert_not_impl_all!(label; *mut u8: Send, Sync);
+assert_not_impl_any!(label; *const u8: Send, Sync);
+assert_not_impl_any!(label; *mut u8: Send, Sync);
+
+assert_obj_safe!(label; core::fmt::Debug);
+
+#[allow(dead_code)]
+struct Foo {
+    x: u8,
+}
+
+assert_fields!(label; Foo: x);
+
+// Unlabeled forms work at module scope.
+const_assert!(true);
+assert_eq_size!(u32, [u8; 4]);
+assert_impl_all!(u8: Send, Sync);
+assert_fields!(Foo: x);
+
+// Helper items are scoped to each expansion, so they never collide.
+#[test]
+fn labels_in_fn() {
+    assert_impl_all!(label; u8: Send);
+    assert_impl_all!(label; u8: Sync);
+    assert_not_impl_any!(label; *const u8: Send);
+    assert_not_impl_any!(label; *const u8: Sync);
+    const_assert!(label; true);
+    const_assert!(label; true);
+}
+
+mod nested {
+    assert_impl_all!(label; u8: Send);
+    assert_not_impl_all!(label; *const u8: Send);
+    const_assert!(label; true);
+}
@@ -0,0 +1,91 @@
+#![no_std]
+#![deny(unsafe_code)]
+
+#[macro_use]
+extern crate static_assertions;
+
+#[allow(dead_code)]
+#[repr(C)]
+struct Header {
+    magic: u32,
+    version: u16,
+    flags: u16,
+}
+
+assert_repr_c_layout!(Header { magic: 0, version: 4, flags: 6 });
+assert_repr_c_layout!(Header { flags: 6, magic: 0, });
+
+#[allow(dead_code)]
+#[repr(C)]
+struct Padded {
+    flag: u8,
+    value: u64,
+    tail: u8,
+}
+
+assert_repr_c_layout!(Padded { flag: 0, value: 8, tail: 16 });
+assert_eq_size!(Padded, [u64; 3]);
+
+#[allow(dead_code)]
+#[repr(C, packed)]
+struct Packed(u8, u32, u16);
+
+assert_repr_c_layout!(Packed { 0: 0, 1: 1, 2: 5 });
+assert_eq_size!(Packed, [u8; 7]);
+
+mod generic {
+    #[allow(dead_code)]
+    #[repr(C)]
+    pub struct Pair<T> {
+        pub first: u8,
+        pub second: T,
+    }
+}
+
+assert_repr_c_layout!(generic::Pair<u32> { first: 0, second: 4 });
+
+#[allow(dead_code)]
+#[repr(transparent)]
+struct Meters(f64);
+
+assert_layout_eq!(u32, i32);
+assert_layout_eq!(u32, i32, char, f32,);
+assert_layout_eq!(Meters, f64);
+assert_layout_eq!([u16; 2], (u16, u16));
+
+// Tightly packed structs, listing fields in any order.
+assert_no_padding!(Header { magic: u32, version: u16, flags: u16 });
+assert_no_padding!(Header { flags: u16, version: u16, magic: u32, });
+assert_no_padding!(Header { magic: u32, version: u16, flags: u16 }, "headers are hashed");
+
+#[allow(dead_code)]
+#[repr(C)]
+struct Rgba(u8, u8, u8, u8);
+
+assert_no_padding!(Rgba { 0: u8, 1: u8, 2: u8, 3: u8 });
+
+#[allow(dead_code)]
+struct Pair<T> {
+    first: T,
+    second: T,
+}
+
+assert_no_padding!(Pair<u32> { first: u32, second: u32 });
+assert_no_padding!(Pair<[u8; 3]> { first: [u8; 3], second: [u8; 3] });
+
+// Packed fields can't be borrowed, and fields can't be moved out of `Drop` types.
+assert_no_padding!(Packed { 0: u8, 1: u32, 2: u16 });
+
+#[allow(dead_code)]
+struct Guard {
+    id: u32,
+}
+
+impl Drop for Guard {
+    fn drop(&mut self) {}
+}
+
+assert_no_padding!(Guard { id: u32 });
+
+// Structs with padding, whose size differs from the sum of their fields.
+const_assert_ne!(core::mem::size_of::<Padded>(), 1 + 8 + 1);
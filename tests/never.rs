@@ -0,0 +1,23 @@
+//! The never type `!`, which can only be named on nightly Rust.
+
+#![cfg(feature = "nightly")]
+#![feature(never_type)]
+#![no_std]
+#![deny(unsafe_code)]
+
+#[macro_use]
+extern crate static_assertions;
+
+use core::fmt::Debug;
+
+assert_impl_all!(!: Send, Sync);
+assert_impl_all!(!: Copy, Clone, Debug, Eq, Ord, core::hash::Hash; "`!` implements most traits");
+assert_impl_all!(! as Sized: Unpin, !Default);
+assert_impl_all!([!, Option<!>, (u8, !)]: Send, Sync, Copy);
+assert_impl_all!(fn() -> !: Send, Copy);
+assert_not_impl_any!(!: Default, Iterator);
+
+#[test]
+fn test_never() {
+    assert_impl_all!(!: Send, Sync);
+}
@@ -8,3 +8,11 @@ assert_obj_safe!(core::fmt::Debug, Send, Sync);
 
 trait ObjSafe {}
 assert_obj_safe!(ObjSafe);
+
+#[allow(dead_code)]
+trait ObjSafeGeneric {
+    fn method(&self) -> u32;
+    fn generic<T>(&self, _: T) where Self: Sized {}
+}
+
+assert_obj_safe!(ObjSafe, ObjSafeGeneric, Iterator<Item = u8>,);
@@ -0,0 +1,22 @@
+#![no_std]
+#![deny(unsafe_code)]
+
+extern crate static_assertions;
+
+use static_assertions::prelude::*;
+
+const_assert!(true);
+const_assert_eq!(2 * 2, 2 + 2);
+
+assert_eq_size!(u32, [u8; 4]);
+assert_impl_all!(u8: Send, Sync);
+assert_impl_all!(for<T: Send> Option<T>: Send);
+assert_not_impl_any!(*const u8: Send);
+assert_zero_sized!(());
+
+#[allow(dead_code)]
+struct Foo {
+    x: u8,
+}
+
+assert_fields!(Foo: x);
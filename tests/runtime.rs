@@ -0,0 +1,56 @@
+//! Tests for the `runtime` feature, under which some assertions become
+//! `debug_assert!`s. Run with `cargo test --features runtime --test runtime`.
+
+#![cfg(feature = "runtime")]
+#![no_std]
+#![deny(unsafe_code)]
+
+#[macro_use]
+extern crate static_assertions;
+
+// Other assertions are still checked at compile-time at the module level.
+assert_size!(u32, 4);
+assert_size_le!(u16, 2);
+assert_transmute_safe!(u32, [u8; 4]);
+
+#[test]
+fn test_passing() {
+    const WIDTH: usize = 640;
+
+    const_assert!(WIDTH > 0);
+    const_assert_eq!(WIDTH, 640, 2 * 320);
+    const_assert_ne!(WIDTH, 0);
+    const_assert_all!(WIDTH >= 320, WIDTH <= 1920);
+    assert_eq_size!(u32, [u8; 4], (u16, u16));
+    assert_eq_align!([i32; 4], i32);
+}
+
+#[test]
+fn test_generic() {
+    // Unlike at compile time, generic parameters can be referred to.
+    fn check<T>() {
+        const_assert!(core::mem::size_of::<T>() <= 8);
+        assert_eq_size!(T, u64);
+    }
+
+    check::<u64>();
+    check::<[u8; 8]>();
+}
+
+#[test]
+#[cfg_attr(debug_assertions, should_panic(expected = "assertion failed"))]
+fn test_const_assert_false() {
+    const_assert!(5 * 5 == 5);
+}
+
+#[test]
+#[cfg_attr(debug_assertions, should_panic(expected = "differ in size"))]
+fn test_eq_size_mismatch() {
+    assert_eq_size!(u32, u8);
+}
+
+#[test]
+#[cfg_attr(debug_assertions, should_panic(expected = "differ in alignment"))]
+fn test_eq_align_mismatch() {
+    assert_eq_align!(u8, u64);
+}
@@ -0,0 +1,21 @@
+#![no_std]
+#![deny(unsafe_code)]
+
+#[macro_use]
+extern crate static_assertions;
+
+use core::fmt::Debug;
+
+#[allow(dead_code)]
+struct Config {
+    name: &'static str,
+    retries: u8,
+}
+
+// Shadowing the auto traits does not affect the assertion.
+#[allow(dead_code)]
+trait Send {}
+
+assert_send_sync!(Config);
+assert_send_sync!(u8, [u8], str, dyn Debug + core::marker::Send + Sync,);
+assert_send_sync!(&'static Config, core::ops::Range<usize>);
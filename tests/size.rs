@@ -0,0 +1,65 @@
+#![no_std]
+#![deny(unsafe_code)]
+
+#[macro_use]
+extern crate static_assertions;
+
+use core::marker::PhantomData;
+
+#[allow(dead_code)]
+struct Tag;
+
+#[allow(dead_code)]
+struct Data {
+    value: u16,
+}
+
+assert_zero_sized!((), PhantomData<u8>);
+assert_zero_sized!(Tag, [u64; 0], ((), Tag), PhantomData<Data>,);
+
+assert_non_zero_sized!(u8);
+assert_non_zero_sized!(Data, (u8, ()), &Tag,);
+
+#[allow(dead_code)]
+struct Packet {
+    header: [u8; 8],
+    payload: [u8; 56],
+}
+
+const BUDGET: usize = 64;
+
+assert_size!(Packet, BUDGET);
+assert_size!(Packet, 64, "packets fill a frame exactly");
+assert_size!(Packet, 64, "`{}` is the size of a frame");
+assert_size!(Data, 2);
+assert_size!(Tag, 0,);
+assert_size!([Data; 3], 3 * core::mem::size_of::<Data>());
+
+assert_size_le!(Packet, BUDGET);
+assert_size_le!(Packet, 64, "packets must fit in a frame");
+assert_size_le!((), 0);
+assert_size_le!(Tag, 0,);
+assert_size_le!(u8, 4, "size must be {small}");
+
+assert_size_lt!(Packet, BUDGET + 1);
+assert_size_lt!(Tag, 1, "tags are zero-sized");
+
+assert_size_ge!(Packet, BUDGET);
+assert_size_ge!((), 0);
+assert_size_ge!(u32, 2, "`u32` is at least 2 bytes",);
+
+assert_size_gt!(Packet, BUDGET - 1);
+assert_size_gt!(u8, 0, "`u8` takes up space");
+assert_size_gt!(u16, 1, "{} must be {}");
+
+#[cfg(target_pointer_width = "16")]
+assert_pointer_width!(16);
+
+#[cfg(target_pointer_width = "32")]
+assert_pointer_width!(32);
+
+#[cfg(target_pointer_width = "64")]
+assert_pointer_width!(64);
+
+#[cfg(target_pointer_width = "64")]
+assert_pointer_width!(8 * 8,);
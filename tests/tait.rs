@@ -0,0 +1,47 @@
+//! Opaque `impl Trait` type aliases, which are unstable.
+
+#![cfg(feature = "nightly")]
+#![feature(type_alias_impl_trait)]
+#![no_std]
+#![deny(unsafe_code)]
+
+#[macro_use]
+extern crate static_assertions;
+
+extern crate alloc;
+
+use alloc::rc::Rc;
+
+type Answer = impl Fn() -> u8;
+
+#[define_opaque(Answer)]
+#[allow(dead_code)]
+fn answer() -> Answer {
+    || 42
+}
+
+type Local = impl Fn() -> u8;
+
+#[define_opaque(Local)]
+#[allow(dead_code)]
+fn local() -> Local {
+    let rc = Rc::new(42);
+    move || *rc
+}
+
+mod bytes {
+    pub type Bytes = impl Iterator<Item = u8>;
+
+    #[define_opaque(Bytes)]
+    #[allow(dead_code)]
+    pub fn bytes() -> Bytes {
+        0..3
+    }
+}
+
+// Auto traits are revealed by the defining function, other traits are not.
+assert_impl_all!(Answer: Send, Sync, Fn() -> u8);
+assert_impl_all!(bytes::Bytes: Iterator<Item = u8>, Send; "byte iterators are sendable");
+assert_not_impl_any!(Local: Send, Sync);
+assert_not_impl_any!(Answer: Copy);
+assert_not_impl_any!(bytes::Bytes: DoubleEndedIterator, Clone);
@@ -0,0 +1,27 @@
+#![no_std]
+#![deny(unsafe_code)]
+
+#[macro_use]
+extern crate static_assertions;
+
+extern crate alloc;
+
+use alloc::string::String;
+
+// Integration tests are compiled with `cfg(test)`, so these are checked.
+test_assert_impl_all!(u8: Copy, Send, Sync);
+test_assert_impl_all!(String: Clone, Send; "strings are cloneable");
+test_assert_impl_all!([u8, u16, u32]: Copy);
+test_assert_impl_all!(for<T: Send> alloc::vec::Vec<T>: Send);
+
+#[cfg(test)]
+#[allow(dead_code)]
+struct OnlyInTests;
+
+// Items that only exist under `cfg(test)` may be asserted on.
+test_assert_impl_all!(OnlyInTests: Sized, Send);
+
+#[test]
+fn test_test_impl_all() {
+    test_assert_impl_all!(u8: Clone);
+}
@@ -4,7 +4,17 @@
 #[macro_use]
 extern crate static_assertions;
 
-use core::ops::Range;
+extern crate alloc;
+
+use alloc::boxed::Box;
+use alloc::string::{FromUtf8Error, String};
+use alloc::vec::Vec;
+use core::borrow::Borrow;
+use core::cell::Cell;
+use core::convert::{Infallible, TryFrom};
+use core::fmt::Debug;
+use core::num::Wrapping;
+use core::ops::{Add, AddAssign, Range};
 
 trait Tri<A: ?Sized, B: ?Sized, C: ?Sized> {}
 
@@ -17,6 +27,277 @@ assert_impl_all!(Range<u8>: Iterator<Item=u8>);
 assert_impl_all!([u8]: Send, Sync, AsRef<[u8]>);
 assert_impl_all!(str: Send, Sync, AsRef<[u8]>,);
 
+mod traits {
+    pub trait Encode {}
+
+    impl Encode for u8 {}
+}
+
+// Absolute and crate-relative paths, also when forwarded by another macro.
+assert_impl_all!(u8: ::core::marker::Send, crate::traits::Encode);
+assert_impl_all!(::core::primitive::u8: ::core::clone::Clone; "bytes are cloneable");
+assert_not_impl_any!(u16: crate::traits::Encode, ::core::ops::Deref);
+
+macro_rules! assert_encodable {
+    ($x:ty: $($t:path),+) => {
+        assert_impl_all!($x: crate::traits::Encode, $($t),+);
+        assert_impl_any!($x: $($t),+);
+    };
+}
+
+assert_encodable!(u8: ::core::marker::Send, ::core::marker::Sync);
+
+// Generic arguments containing brackets, with trailing commas, also when
+// forwarded as raw tokens through nested macros.
+assert_impl_all!(String: AsRef<[u8]>, Borrow<str>,);
+assert_impl_all!(Vec<u8>: AsRef<[u8]>,);
+assert_impl_all!(Vec<u8>: AsRef<[u8]>, AsRef<Vec<u8>>, Borrow<[u8]>,; "vectors are byte slices");
+
+macro_rules! forward {
+    ($($t:tt)*) => {
+        assert_impl_all!($($t)*);
+    };
+}
+
+macro_rules! forward_nested {
+    ($($t:tt)*) => {
+        forward!($($t)*);
+    };
+}
+
+forward!(String: AsRef<[u8]>, Borrow<str>,);
+forward_nested!(Vec<u8>: AsRef<[u8]>,);
+forward_nested!(for<T> Vec<T>: AsRef<[T]>, Borrow<[T]>,);
+assert_not_impl_any!(Vec<u8>: AsRef<u32>, AsRef<[u16]>);
+
+// Long lists of fully-qualified paths, such as from other macros, stay within
+// the recursion limit.
+assert_impl_all!(u8:
+    ::core::marker::Send, ::core::marker::Sync, ::core::marker::Copy,
+    ::core::marker::Unpin, ::core::marker::Sized, ::core::clone::Clone,
+    ::core::fmt::Debug, ::core::fmt::Display, ::core::default::Default,
+    ::core::cmp::PartialEq, ::core::cmp::Eq, ::core::cmp::PartialOrd,
+    ::core::cmp::Ord, ::core::hash::Hash, ::core::str::FromStr,
+    ::core::convert::From<u8>, ::core::convert::Into<u16>,
+    ::core::convert::TryFrom<u32>, ::core::ops::Add<u8>, ::core::ops::Not,
+);
+assert_impl_all!(for<T: Copy + Send + Sync + Unpin + Debug + Ord + core::hash::Hash> Option<T>:
+    core::marker::Send, core::marker::Sync, core::marker::Copy,
+    core::marker::Sized, core::clone::Clone, core::fmt::Debug,
+    core::default::Default, core::cmp::PartialEq, core::cmp::Eq,
+    core::cmp::PartialOrd, core::cmp::Ord, core::hash::Hash,
+    core::convert::From<T>, core::convert::From<Option<T>>,
+    core::iter::IntoIterator<Item = T>, core::marker::Unpin,
+);
+
+// Repeated commas from generated trait lists are ignored.
+macro_rules! forward_list {
+    ($type:ty: $($($bound:path)?),*) => {
+        assert_impl_all!($type: $($($bound)?,)*);
+    };
+}
+
+forward_list!(u8: Send, , Sync);
+forward_list!(String: Clone,,,);
+assert_impl_all!(u8: Send,,);
+assert_impl_all!(u8: , Send,, Sync,);
+assert_impl_all!(u8: Send,, Copy,,; "bytes are copyable",);
+assert_impl_all!([u8, u16]: Send,, Sync,);
+assert_impl_all!(for<T: Send> Vec<T>: Send,,);
+
+// Multiple types at once, while `[T]` remains a slice.
+assert_impl_all!([u8, u16, Range<u8>]: Send, Sync);
+assert_impl_all!([&str, [u8; 2],]: Copy, AsRef<[u8]>);
+assert_impl_all!([u8, ()]: Send; "units and bytes are sendable");
+
+// Tuples of any arity, including single-element and nested ones.
+assert_impl_all!((): Send, Sync, Copy);
+assert_impl_all!((u8,): Send, Sync, Copy);
+assert_impl_all!((u8, u16, u32, u64, i8, i16, i32, i64): Send, Sync, Copy, Default);
+assert_impl_all!(((u8, u8), u16): Send, Debug);
+assert_impl_all!((((u8,),),): Send; "nested tuples are sendable");
+assert_impl_all!([(u8,), (u8, u16,)]: Send, Copy);
+assert_impl_all!((u8,) as Sized: Send);
+assert_impl_all!(for<T: Send> (T,): Send);
+assert_not_impl_any!((u8, Cell<u8>, u16): Sync);
+assert_not_impl_any!(((u8,), (Cell<u8>,)): Sync);
+
+// Lifetime bounds, alone and mixed with traits.
+assert_impl_all!(String: 'static);
+assert_impl_all!(String: Send + 'static, Sync);
+assert_impl_all!(&'static str: 'static, Copy,);
+assert_impl_all!([u8, Vec<String>]: 'static; "owned data lives forever");
+assert_impl_all!(for<'a> &'a u8: 'a + Send);
+assert_impl_all!(for<'a, 'b: 'a> &'b u8: 'a);
+assert_impl_all!(for<T: 'static> Vec<T>: 'static);
+
+// Shared and mutable references, which differ in their auto traits.
+assert_impl_all!(&'static mut [u8]: Send, Sync);
+assert_impl_all!(&'static mut Cell<u8>: Send, !Sync, !Copy);
+assert_impl_all!(&'static Cell<u8>: Copy, !Send, !Sync);
+assert_impl_all!([&'static mut u8, &'static mut str]: Send, Sync; "mutable references are thread-safe");
+assert_impl_all!(for<'a> &'a mut Cell<u8>: Send + 'a);
+assert_impl_all!(for<'a, T: Send + 'a> &'a mut T: Send);
+assert_impl_all!(for<'a, T: Sync + 'a> &'a T: Send, Copy);
+assert_not_impl_any!(&'static mut Cell<u8>: Sync, Clone);
+assert_not_impl_any!(&'static Cell<u8>: Send, Sync);
+
+// Asserting `Sized` is not relaxed for dynamically-sized types.
+assert_impl_all!(&str: Sized, Send);
+assert_impl_all!([u8; 4]: Send, Sized);
+assert_impl_all!(u8: Sized; "u8 is sized");
+assert_not_impl_any!(str: Sized);
+assert_not_impl_any!([u8]: Sized);
+
+// Sizedness made explicit with `as Sized`.
+assert_impl_all!([u8]: Send);
+assert_impl_all!(u32 as Sized: Copy);
+assert_impl_all!(&'static [u8] as Sized: Copy, Send, Sync,);
+assert_impl_all!(Range<u8> as Sized: Iterator<Item = u8>; "ranges are sized iterators");
+assert_impl_all!(<Range<u8> as Iterator>::Item as Sized: Copy);
+
+trait Stream {
+    type Item;
+    type Error;
+}
+
+#[allow(dead_code)]
+struct Frame;
+
+#[allow(dead_code)]
+struct MyStream;
+
+impl Stream for MyStream {
+    type Item = Frame;
+    type Error = ();
+}
+
+assert_impl_all!(MyStream: Stream<Item = Frame, Error = ()>);
+assert_impl_all!(MyStream: Send, Stream<Error = (), Item = Frame>, Sync,);
+assert_impl_all!(MyStream: Stream<Item = Frame, Error = ()>; "streams yield frames");
+assert_impl_all!(for<T: Stream<Item = Frame, Error = ()>> Option<T>: Sized);
+
+// Generic arguments mixed with associated type bindings.
+assert_impl_all!(String: TryFrom<Vec<u8>>);
+assert_impl_all!(String: TryFrom<Vec<u8>, Error = FromUtf8Error>, From<&'static str>);
+assert_impl_all!(u16: TryFrom<u8, Error = Infallible>, From<u8>);
+assert_impl_all!(Option<Vec<u8>>: TryFrom<Option<Vec<u8>>, Error = Infallible>);
+assert_not_impl_any!(u16: TryFrom<u8, Error = FromUtf8Error>);
+
+trait Codec<T: ?Sized> {
+    type Encoded;
+    type Error;
+}
+
+impl Codec<[u8]> for MyStream {
+    type Encoded = Vec<Option<(u8, Vec<u8>)>>;
+    type Error = Infallible;
+}
+
+assert_impl_all!(MyStream: Codec<[u8], Encoded = Vec<Option<(u8, Vec<u8>)>>, Error = Infallible>);
+assert_impl_all!(MyStream: Codec<[u8], Error = Infallible, Encoded = Vec<Option<(u8, Vec<u8>)>>>, Send);
+assert_impl_all!(for<T: Codec<[u8], Error = Infallible>> Option<T>: Sized);
+assert_not_impl_any!(MyStream: Codec<[u8], Encoded = Vec<u8>>, Codec<str, Error = Infallible>);
+
+// Generic associated types with lifetime, type, and const parameters.
+trait Collection {
+    type Iter<'a>: Iterator
+    where
+        Self: 'a;
+    type Map<V>;
+    type Chunk<const N: usize>;
+}
+
+#[allow(dead_code)]
+struct Slice<'a>(&'a [u8]);
+
+impl<'a> Iterator for Slice<'a> {
+    type Item = &'a u8;
+    fn next(&mut self) -> Option<&'a u8> {
+        None
+    }
+}
+
+#[allow(dead_code)]
+struct Bytes(Vec<u8>);
+
+impl Collection for Bytes {
+    type Iter<'a> = Slice<'a>;
+    type Map<V> = Vec<(u8, V)>;
+    type Chunk<const N: usize> = [u8; N];
+}
+
+assert_impl_all!(Bytes: for<'a> Collection<Iter<'a> = Slice<'a>>);
+assert_impl_all!(Bytes: Collection<Map<bool> = Vec<(u8, bool)>, Chunk<4> = [u8; 4]>, Send);
+assert_impl_all!(Bytes: for<'a> Collection<Iter<'a> = Slice<'a>, Map<()> = Vec<(u8, ())>>);
+assert_impl_all!(for<V> Bytes: Collection<Map<V> = Vec<(u8, V)>>);
+assert_impl_all!(for<T: Collection<Map<u8> = Vec<(u8, u8)>>> Option<T>: Sized);
+assert_not_impl_any!(Bytes: Collection<Map<bool> = Vec<bool>>, Collection<Chunk<4> = [u8; 2]>);
+
+// Traits with default generic parameters, such as `Add<Rhs = Self>`.
+assert_impl_all!(i32 as Sized: Add, Add<Output = i32>);
+assert_impl_all!(i32 as Sized: Add, Add<Output = i32>; "i32 is addable");
+assert_impl_all!(i32: Add<i32>, Add<&'static i32>, Add<i32, Output = i32>);
+assert_impl_all!(String: Add<&'static str, Output = String>, AddAssign<&'static str>);
+assert_impl_all!(Wrapping<u8> as Sized: Add, Add<Output = Wrapping<u8>>);
+assert_not_impl_any!(i32: Add<i64>, Add<u8>, Add<i32, Output = i64>);
+assert_not_impl_any!(String: Add<String>, Add<char>);
+
+// Supertraits are required of the type and implied by generic bounds.
+trait Loggable: Debug {}
+
+#[allow(dead_code)]
+#[derive(Debug)]
+struct Event;
+
+impl Loggable for Event {}
+
+assert_impl_all!(Event: Loggable, Debug);
+assert_impl_all!(for<T: Loggable> Vec<T>: Debug);
+assert_impl_all!(for<T: Loggable> (T, Option<T>): Debug);
+assert_not_impl_any!(Frame: Loggable, Debug);
+
+// Traits implemented through blanket implementations.
+trait BytesExt {}
+
+impl<T: AsRef<[u8]> + ?Sized> BytesExt for T {}
+
+assert_impl_all!(Vec<u8>: BytesExt);
+assert_impl_all!(str: BytesExt, AsRef<[u8]>);
+assert_impl_all!([&'static str, String, [u8; 4]]: BytesExt);
+assert_impl_all!(for<'a, T: AsRef<[u8]> + 'a> &'a T: BytesExt);
+assert_not_impl_any!(u32: BytesExt);
+assert_not_impl_any!(Vec<u16>: BytesExt);
+
+// Negative bounds, alone and mixed with positive ones.
+#[allow(dead_code)]
+struct MyPtr(*const u8);
+
+assert_impl_all!(MyPtr: !Send);
+assert_impl_all!(MyPtr: !Send, !Sync, !Clone,);
+assert_impl_all!(Cell<u8>: Clone, !Sync, Send, Default);
+assert_impl_all!(Cell<u8>: !Sync, Clone; "cells are cloneable but not shareable");
+assert_impl_all!(u8: Into<u16>, !Into<i8>, !Stream<Item = Frame, Error = ()>);
+assert_impl_all!([MyPtr, Cell<MyPtr>]: !Sync);
+
+// Trait objects, including auto traits and lifetimes, before the colon.
+assert_impl_all!(dyn Debug + Send + Sync: Debug, Send, Sync);
+assert_impl_all!(dyn Debug + Send + 'static: Debug, Send);
+assert_impl_all!(for<'a> dyn Debug + Sync + 'a: Sync);
+assert_impl_all!(&'static (dyn Debug + Sync): Debug, Send, Copy);
+assert_not_impl_any!(dyn Debug: Send, Sync);
+assert_not_impl_any!(dyn Debug + Send: Sync, Sized);
+
+// Trait objects with several bounds nested within generic arguments.
+assert_impl_all!(Box<dyn Iterator<Item = u8> + Send>: Send, Iterator<Item = u8>, !Sync);
+assert_impl_all!(Box<dyn Iterator<Item = Vec<u8>> + Send + Sync>: Send, Sync);
+assert_impl_all!(Vec<Box<dyn Debug + Send + Sync + 'static>>: Send, Sync, Debug);
+assert_impl_all!(Option<&'static (dyn Debug + Sync)>: Send, Copy);
+assert_impl_all!([Box<dyn Debug + Send>, Vec<Box<dyn Debug + Send>>]: Send, !Sync);
+assert_impl_all!(for<'a> Box<dyn Iterator<Item = &'a u8> + Send + 'a>: Send);
+assert_not_impl_any!(Box<dyn Iterator<Item = u8>>: Send, Sync);
+assert_not_impl_any!(Box<dyn Iterator<Item = u8> + Sync>: Send);
+
 assert_impl_any!((): Send, Sync);
 assert_impl_any!((): Send, From<u8>);
 assert_impl_any!((): From<u8>, From<u16>, Send);
@@ -37,3 +318,207 @@ assert_impl_one!(Foo: C, B);
 assert_impl_one!(Foo: A, B, C);
 assert_impl_one!(Foo: B, C, A);
 assert_impl_one!(Foo: C, A, B);
+
+assert_not_impl_all!(*const u8: Send, Sync);
+assert_not_impl_all!(core::cell::Cell<u8>: Send, Sync);
+assert_not_impl_all!(Foo: A, B);
+assert_not_impl_all!(Foo: B, C,);
+
+assert_not_impl_any!(*const u8: Send, Sync);
+assert_not_impl_any!(Foo: A, C);
+assert_not_impl_any!(Foo: Copy, Clone,);
+
+assert_impl_all!(u8: Send, Sync; "`u8` is thread-safe");
+assert_impl_all!(Foo: B, Send,; "`Foo` implements `B`",);
+
+assert_impl_all!(for<T: Send> Option<T>: Send);
+assert_impl_all!(for<T: Send, U: Send + Clone> (T, U): Send);
+assert_impl_all!(for<T: ?Sized + Sync + 'static> &'static T: Send, Sync, Copy);
+assert_impl_all!(for<'a, T: 'a + ?Sized + Sync,> &'a T: Send);
+assert_impl_all!(for<T: Iterator<Item = Option<u8>>> core::iter::Peekable<T>: Iterator<Item = Option<u8>>);
+assert_impl_all!(for<T: Send + Into<Option<u8>>> core::cell::Cell<T>: Send,);
+assert_impl_all!(for<T: Send> Vec<T>: Send; "vectors are sendable");
+assert_impl_all!(for<'a, T: Sync + 'a, const N: usize> [&'a T; N]: Send, Copy,; "arrays of references are copyable",);
+
+// Generics written as a `where` clause.
+assert_impl_all! {
+    where T: Send + Clone, U: Send + Clone => (T, U): Send, Clone
+}
+assert_impl_all! {
+    where
+        'a,
+        T: Send + Sync + Clone + 'a,
+        U: Send + Sync + ?Sized,
+    => (Vec<T>, &'a [T], Box<U>): Send, Sync
+}
+assert_impl_all!(where T: Iterator<Item = Option<u8>> => core::iter::Peekable<T>: Iterator<Item = Option<u8>>);
+assert_impl_all!(where T: Into<Vec<u8>>, U: Copy, => Option<(T, U)>: Sized);
+assert_impl_all!(where const N: usize, T: Copy => [T; N]: Copy, Clone);
+assert_impl_all!(where T: Clone => Option<T>: Clone; "options are cloneable");
+
+// Wrappers of possibly dynamically-sized parameters.
+#[allow(dead_code)]
+struct MyBox<T: ?Sized>(Box<T>);
+
+assert_impl_all!(for<T: ?Sized + Send> MyBox<T>: Send, Sized);
+assert_impl_all!(for<T: ?Sized + Send + Sync> MyBox<T>: Send, Sync);
+assert_impl_all!(for<T: Send> MyBox<[T]>: Send);
+assert_impl_all!([MyBox<str>, MyBox<dyn Debug + Send>, MyBox<[u8]>]: Send);
+assert_impl_all!(MyBox<dyn Debug + Send>: !Sync);
+assert_not_impl_any!(MyBox<dyn Debug>: Send, Sync);
+assert_not_impl_any!(MyBox<Cell<u8>>: Sync);
+
+// The never type can only be named as a return type on stable Rust.
+assert_impl_all!(fn() -> !: Send, Sync, Copy);
+
+type Parser = fn(&str) -> Option<&str>;
+
+assert_impl_all!(Parser: for<'a> Fn(&'a str) -> Option<&'a str>);
+assert_impl_all!(Parser: Copy, for<'a> FnMut(&'a str) -> Option<&'a str>, Send,);
+assert_impl_all!(Parser: for<'a> FnOnce(&'a str) -> Option<&'a str>; "parses");
+assert_impl_all!(for<T: for<'a> Fn(&'a u8)> T: Sized, for<'b> Fn(&'b u8));
+
+// Parenthesized `Fn` sugar with elided lifetimes, return types, and `+`.
+type Matcher = fn(&str, usize) -> bool;
+
+assert_impl_all!(Matcher: Fn(&str, usize) -> bool + Send);
+assert_impl_all!(Matcher: Fn(&str, usize) -> bool + FnOnce(&str, usize) -> bool + Send + Sync);
+assert_impl_all!(Matcher: Fn(&str, usize) -> bool, FnMut(&str, usize) -> bool + Copy, Sync,);
+assert_impl_all!(Matcher: Fn(&str, usize) -> bool + Send; "matches prefixes");
+assert_impl_all!([Matcher, fn(&str, usize) -> bool]: Fn(&str, usize) -> bool + Send);
+assert_impl_all!(fn(&mut Vec<u8>) -> &[u8]: Fn(&mut Vec<u8>) -> &[u8] + 'static);
+assert_impl_all!(for<F: Fn(&str) -> Option<&str> + Send> Option<F>: Send);
+assert_not_impl_any!(Matcher: Fn(&str) -> bool, Fn(&str, usize) -> usize, Fn(&'static str, usize));
+
+assert_impl_ref!(u8: Send, Sync);
+assert_impl_ref!(str: Send, Sync, AsRef<[u8]>,);
+assert_impl_ref!([u8]: IntoIterator);
+assert_impl_ref!(core::slice::Iter<'static, u8>: Send, Sync);
+
+// Assertions are items, so they are allowed within const contexts.
+#[allow(dead_code)]
+const fn checked_len() -> usize {
+    assert_impl_all!(u32: Copy, Send);
+    assert_impl_all!(u32: Copy; "lengths are copied");
+    assert_impl_all!(for<T: Copy + Send + Sync + Debug + Ord + core::hash::Hash> Option<T>: Copy);
+    assert_not_impl_any!(*const u8: Send);
+    4
+}
+
+#[allow(dead_code)]
+const HEADER: [u8; checked_len()] = [0; { assert_impl_all!(u8: Copy); 4 }];
+
+#[allow(dead_code)]
+struct Buf<const N: usize>([u8; N]);
+
+impl<const N: usize> Default for Buf<N> where [u8; N]: Default {
+    fn default() -> Self {
+        Buf(Default::default())
+    }
+}
+
+#[allow(dead_code)]
+struct Matrix<const ROWS: usize, const COLS: usize>([[f32; COLS]; ROWS]);
+
+assert_impl_all!(Buf<16>: Send, Default);
+assert_impl_all!(Buf<{ 8 * 4 }>: Default);
+assert_impl_all!(Matrix<2, 3>: Send, Sync);
+assert_impl_all!(for<const N: usize> Buf<N>: Send, Sync);
+assert_impl_all!(for<const ROWS: usize, const COLS: usize> Matrix<ROWS, COLS>: Send);
+
+// A const parameter that shares its name with a type, here the trait `C`.
+assert_impl_all!(for<const C: usize> Matrix<2, { C }>: Send, Sync);
+assert_not_impl_any!(Buf<33>: Default);
+
+trait Deserialize<'de> {}
+
+#[allow(dead_code)]
+struct Owned;
+
+#[allow(dead_code)]
+struct Borrowed<'a>(&'a str);
+
+impl<'de> Deserialize<'de> for Owned {}
+impl<'de: 'a, 'a> Deserialize<'de> for Borrowed<'a> {}
+impl<'de> Deserialize<'de> for &'de str {}
+
+assert_impl_all!(for<'de> Owned: Deserialize<'de>);
+assert_impl_all!(Owned: for<'de> Deserialize<'de>, Send);
+assert_impl_all!(for<'de> &'de str: Deserialize<'de>, Copy);
+assert_impl_all!(for<'a, 'de: 'a> Borrowed<'a>: Deserialize<'de>, Sync);
+assert_impl_all!(for<'a, 'b, 'de: 'a + 'b> (Borrowed<'a>, &'b u8): Send, Sync);
+
+#[test]
+fn test_impl_val() {
+    let mut count = 0u32;
+    let mut filter = |byte: u8| {
+        count += 1;
+        byte.is_ascii()
+    };
+
+    assert_impl_val!(filter: FnMut(u8) -> bool);
+    assert_impl_val!(filter: FnMut(u8) -> bool, FnOnce(u8) -> bool,);
+    assert!(filter(b'a'));
+    assert_eq!(count, 1);
+
+    let is_zero = |byte: u8| byte == 0;
+    assert_impl_val!(is_zero: Fn(u8) -> bool, Copy, Send, Sync);
+    assert_impl_val!((|x: u8, y: u8| x < y): Fn(u8, u8) -> bool, Fn(u8, u8) -> bool + Copy);
+    assert_impl_val!({ |s: &str| s.len() }: for<'a> Fn(&'a str) -> usize);
+    let matches = |s: &str, n: usize| s.len() == n;
+    assert_impl_val!(matches: Fn(&str, usize) -> bool + Send, FnOnce(&str, usize) -> bool + Sync);
+
+    // Non-closure values work too.
+    assert_impl_val!(count: Copy, PartialEq<u32>, Into<u64>);
+    assert_impl_val!((*"str"): AsRef<[u8]>);
+}
+
+// `Self` within trait default methods and inherent methods.
+trait Snapshot: Clone + Send {
+    fn snapshot(&self) -> Self {
+        assert_impl_all!(Self: Clone);
+        assert_impl_all!(Self: Clone, Send, Sized,);
+        assert_impl_all!(Self: Clone; "snapshots are made by cloning");
+        assert_impl_all!(Self: Send, !Sync, !Copy);
+        self.clone()
+    }
+}
+
+#[derive(Clone)]
+struct Config {
+    id: u32,
+}
+
+impl Config {
+    fn new(id: u32) -> Self {
+        assert_impl_all!(Self: Clone, Send, Sync);
+        assert_impl_all!(Self: Clone, !Copy);
+        assert_not_impl_any!(Config: Copy);
+        Config { id }
+    }
+}
+
+impl Snapshot for Config {}
+
+#[test]
+fn test_impl_self() {
+    let config = Config::new(7);
+    assert_eq!(config.snapshot().id, 7);
+}
+
+#[test]
+fn test_iter_item() {
+    let mut numbers = (0u8..5).map(|x| x as u32);
+    assert_iter_item!(numbers, u32);
+    assert_iter_item!((0u8..5).map(|x| x as u32), u32,);
+    assert_eq!(numbers.next(), Some(0));
+
+    let words = ["a", "bc", "def"];
+    assert_iter_item!(words.iter(), &&str);
+    assert_iter_item!(words.iter().copied().map(str::len).enumerate(), (usize, usize));
+    assert_iter_item!(core::iter::empty::<Option<u8>>().flatten(), u8);
+
+    let dynamic: &mut dyn Iterator<Item = u16> = &mut (0u16..3);
+    assert_iter_item!(*dynamic, u16);
+    assert_eq!(dynamic.count(), 3);
+}
@@ -0,0 +1,58 @@
+#![no_std]
+#![deny(unsafe_code)]
+
+#[macro_use]
+extern crate static_assertions;
+
+extern crate alloc;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::ops::Deref;
+
+trait Shape {
+    fn area(&self) -> u32;
+    fn name() -> &'static str;
+}
+
+#[allow(dead_code)]
+struct Square(u32);
+
+impl Shape for Square {
+    fn area(&self) -> u32 {
+        self.0 * self.0
+    }
+
+    fn name() -> &'static str {
+        "square"
+    }
+}
+
+// Dereferences to a `Square`, but does not implement `Shape` itself.
+#[allow(dead_code)]
+struct Boxed(Square);
+
+impl Deref for Boxed {
+    type Target = Square;
+
+    fn deref(&self) -> &Square {
+        &self.0
+    }
+}
+
+assert_trait_method!(Square: Shape::area);
+assert_trait_method!(Square: Shape::name);
+assert_trait_method!(<Boxed as Deref>::Target: Shape::area);
+assert_trait_method!(Vec<u8>: IntoIterator::into_iter);
+assert_trait_method!(String: core::str::FromStr::from_str);
+assert_trait_method!(str: AsRef<[u8]>::as_ref);
+assert_trait_method!([u8]: ::core::borrow::Borrow<[u8]>::borrow);
+assert_trait_method!(Square: self::Shape::area);
+assert_not_impl_any!(Boxed: Shape);
+
+#[test]
+fn test_trait_method() {
+    assert_trait_method!(Square: Shape::area);
+    assert_eq!(Square(3).area(), 9);
+    assert_eq!(Boxed(Square(2)).area(), 4);
+}
@@ -0,0 +1,33 @@
+#![no_std]
+#![deny(unsafe_code)]
+
+#[macro_use]
+extern crate static_assertions;
+
+use core::fmt::Debug;
+
+#[allow(dead_code)]
+trait Parent<T: ?Sized> {}
+#[allow(dead_code)]
+trait Child<T: ?Sized>: Parent<T> + Debug {}
+#[allow(dead_code)]
+trait Sub: Sized + Copy {}
+
+assert_trait_sub_all!(Ord: PartialOrd, Eq);
+assert_trait_sub_all!(Ord: PartialOrd<Self>, PartialEq<Self>,);
+assert_trait_sub_all!(Copy: Clone, Sized);
+assert_trait_sub_all!(Child<str>: Parent<str>, Debug);
+assert_trait_sub_all!(Sub: Sized, Copy, Clone);
+assert_trait_sub_all!(Iterator<Item = u8>: Iterator);
+assert_trait_sub_all!(Iterator: IntoIterator);
+assert_trait_sub_all!(?Sized + Copy: Clone, Sized);
+assert_trait_sub_all!(?Sized + Child<str>: Parent<str>, Debug,);
+
+assert_trait_super_all!(Debug: Child<u8>, Child<[u8]>);
+assert_trait_super_all!(Sized: Copy, Sub);
+
+#[allow(dead_code)]
+trait Grandchild: Child<u8> {}
+
+assert_trait_super_all!(Parent<u8>: Child<u8>, Grandchild,);
+assert_trait_super_all!(PartialEq: Eq, Ord, PartialOrd);
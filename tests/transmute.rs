@@ -0,0 +1,31 @@
+#![no_std]
+#![deny(unsafe_code)]
+
+#[macro_use]
+extern crate static_assertions;
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+#[allow(dead_code)]
+#[repr(transparent)]
+struct Meters(f64);
+
+#[allow(dead_code)]
+#[repr(C)]
+struct Pair {
+    a: u16,
+    b: u16,
+}
+
+assert_transmute_safe!(u32, [u8; 4]);
+assert_transmute_safe!(u32, char, align);
+assert_transmute_safe!(Meters, u64, align,);
+assert_transmute_safe!(Pair, [u16; 2], align);
+assert_transmute_safe!(&'static u8, *const u8, align);
+assert_transmute_safe!((), [u64; 0]);
+
+// Same size, but rejected since they need to be dropped.
+assert_eq_size!(Vec<u8>, [usize; 3]);
+assert_not_impl_any!(Vec<u8>: Copy);
@@ -9,6 +9,74 @@ assert_type_eq_all!([u8], [u8]);
 #[allow(dead_code)]
 type X = u8;
 
+#[allow(unused_parens)]
 mod m {
     assert_type_eq_all!(super::X, u8, (super::X));
 }
+
+#[allow(dead_code)]
+type Handle = u64;
+
+#[allow(dead_code)]
+type Pair<T> = (T, T);
+
+assert_type_eq_all!(Handle, u64, platform::Handle);
+assert_type_eq_all!(&'static Handle, &'static u64);
+assert_type_eq_all!(&'static mut [X], &'static mut [u8]);
+assert_type_eq_all!(Option<Pair<u8>>, Option<(u8, X)>);
+
+mod platform {
+    pub type Handle = super::Handle;
+}
+
+#[allow(dead_code)]
+type Callback = unsafe extern "C" fn(u32) -> i32;
+
+#[allow(dead_code)]
+type Visitor = for<'a> fn(&'a [u8]) -> Option<&'a u8>;
+
+assert_fn_ptr_compatible!(Callback, unsafe extern "C" fn(u32) -> i32);
+assert_fn_ptr_compatible!(Visitor, fn(&[u8]) -> Option<&u8>);
+assert_fn_ptr_compatible!(fn(), extern "Rust" fn(), fn() -> (),);
+
+#[allow(dead_code)]
+fn checksum(data: &[u8]) -> u32 {
+    data.len() as u32
+}
+
+#[allow(dead_code)]
+fn first(data: &[u8]) -> Option<&u8> {
+    data.first()
+}
+
+#[allow(dead_code)]
+fn parse<T: Default>() -> T {
+    T::default()
+}
+
+#[allow(dead_code, clippy::too_many_arguments)]
+fn twelve(_: u8, _: u8, _: u8, _: u8, _: u8, _: u8, _: u8, _: u8, _: u8, _: u8, _: u8, _: u8) -> Handle {
+    0
+}
+
+#[allow(dead_code)]
+struct Builder;
+
+#[allow(dead_code)]
+struct Request;
+
+impl Builder {
+    #[allow(dead_code)]
+    fn build(self) -> Request {
+        Request
+    }
+}
+
+assert_return_type_eq!(checksum, u32);
+assert_return_type_eq!(first, Option<&u8>);
+assert_return_type_eq!(parse::<u8>, X);
+assert_return_type_eq!(twelve, u64);
+assert_return_type_eq!(Builder::build, Request);
+assert_return_type_eq!(core::mem::size_of::<u8>, usize,);
+assert_return_type_eq!(|| (), ());
+assert_return_type_eq!(|x: u8, y: u16| (x, y), (u8, u16));
@@ -5,3 +5,12 @@
 extern crate static_assertions;
 
 assert_type_ne_all!(u8, u16, u32);
+
+#[allow(dead_code)]
+struct Wrapper(u8);
+
+#[allow(dead_code)]
+type Alias = u16;
+
+assert_type_ne_all!(Wrapper, u8, Alias, [u8; 1], (u8,),);
+assert_type_ne_all!(&'static u8, &'static mut u8, *const u8, *mut u8);
@@ -0,0 +1,27 @@
+//! Tests for the compiler errors emitted when assertions fail.
+//!
+//! The expected `.stderr` files depend on the compiler version, so these only
+//! run with `RUSTFLAGS='--cfg ui_tests'`, which CI sets for the compiler that
+//! the files are kept up to date with: stable, or nightly for those under
+//! `tests/ui/nightly`. Run with `TRYBUILD=overwrite` to update them after
+//! checking that the new errors are still helpful.
+
+#[test]
+#[cfg(all(ui_tests, not(any(feature = "diagnostics", feature = "nightly", feature = "runtime"))))]
+fn ui() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+    t.pass("tests/ui/pass/*.rs");
+}
+
+#[test]
+#[cfg(all(ui_tests, feature = "diagnostics"))]
+fn diagnostics() {
+    trybuild::TestCases::new().compile_fail("tests/ui/diagnostics/*.rs");
+}
+
+#[test]
+#[cfg(all(ui_tests, feature = "nightly"))]
+fn nightly() {
+    trybuild::TestCases::new().compile_fail("tests/ui/nightly/*.rs");
+}
@@ -0,0 +1,8 @@
+#[macro_use]
+extern crate static_assertions;
+
+const LEN: usize = 3;
+
+const_assert!(LEN >= 4);
+
+fn main() {}
@@ -0,0 +1,6 @@
+#[macro_use]
+extern crate static_assertions;
+
+const_assert!(!0);
+
+fn main() {}
@@ -0,0 +1,13 @@
+#[macro_use]
+extern crate static_assertions;
+
+use std::cell::Cell;
+
+struct Slot<'a> {
+    value: Cell<&'a str>,
+}
+
+assert_covariant!(Slot<'a>);
+assert_covariant!(fn(&'a str));
+
+fn main() {}
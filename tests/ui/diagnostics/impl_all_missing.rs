@@ -0,0 +1,8 @@
+#[macro_use]
+extern crate static_assertions;
+
+struct Foo;
+
+assert_impl_all!(Foo: Clone, Send);
+
+fn main() {}
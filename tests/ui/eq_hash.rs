@@ -0,0 +1,9 @@
+#[macro_use]
+extern crate static_assertions;
+
+#[derive(PartialEq, Eq)]
+struct Key(u32);
+
+assert_eq_hash!(Key);
+
+fn main() {}
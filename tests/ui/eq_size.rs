@@ -0,0 +1,6 @@
+#[macro_use]
+extern crate static_assertions;
+
+assert_eq_size!(u32, u8);
+
+fn main() {}
@@ -0,0 +1,14 @@
+#[macro_use]
+extern crate static_assertions;
+
+use std::io::Read;
+
+trait ReadExt {}
+
+impl<T: Read> ReadExt for T {}
+
+struct Socket;
+
+assert_impl_all!(Socket: ReadExt);
+
+fn main() {}
@@ -0,0 +1,11 @@
+#[macro_use]
+extern crate static_assertions;
+
+use std::cell::Cell;
+use std::rc::Rc;
+
+struct Token(u32);
+
+assert_impl_all!([Token, Rc<Token>, Cell<u8>]: Send, Sync);
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[macro_use]
+extern crate static_assertions;
+
+assert_impl_all!(u8:);
+assert_impl_all!(u8: ,,; "no traits");
+
+fn main() {}
@@ -0,0 +1,12 @@
+#[macro_use]
+extern crate static_assertions;
+
+use std::rc::Rc;
+
+struct Shared {
+    inner: Rc<u8>,
+}
+
+assert_impl_all!(Shared: Send);
+
+fn main() {}
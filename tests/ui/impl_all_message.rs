@@ -0,0 +1,10 @@
+#[macro_use]
+extern crate static_assertions;
+
+#[derive(Clone)]
+struct Config;
+
+assert_impl_all!(Config: Clone, Default; "`Config` is cloned from defaults");
+assert_impl_all!(for<T: Clone> Vec<T>: Clone, Copy; "`{Self}` is copied");
+
+fn main() {}
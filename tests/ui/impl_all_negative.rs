@@ -0,0 +1,19 @@
+#[macro_use]
+extern crate static_assertions;
+
+use std::rc::Rc;
+
+assert_impl_all!(for<T: Send> Vec<T>: Send, !Sync);
+
+struct Handle(Rc<u8>);
+
+impl Handle {
+    fn new() -> Self {
+        assert_impl_all!(Self: !Unpin);
+        Handle(Rc::new(0))
+    }
+}
+
+fn main() {
+    let _ = Handle::new().0;
+}
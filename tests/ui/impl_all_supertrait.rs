@@ -0,0 +1,12 @@
+#[macro_use]
+extern crate static_assertions;
+
+use std::fmt::Debug;
+
+trait Loggable: Debug {}
+
+struct Opaque;
+
+assert_impl_all!(Opaque: Loggable);
+
+fn main() {}
@@ -0,0 +1,10 @@
+#[macro_use]
+extern crate static_assertions;
+
+use std::rc::Rc;
+
+assert_impl_all! {
+    where T: Send, U: Send + Sync => (T, Rc<U>): Send
+}
+
+fn main() {}
@@ -0,0 +1,6 @@
+#[macro_use]
+extern crate static_assertions;
+
+assert_impl_one!(u8: Copy, Clone);
+
+fn main() {}
@@ -0,0 +1,6 @@
+#[macro_use]
+extern crate static_assertions;
+
+assert_layout_eq!(u32, i32, u64);
+
+fn main() {}
@@ -0,0 +1,9 @@
+#![feature(const_trait_impl, const_default, const_cmp)]
+
+#[macro_use]
+extern crate static_assertions;
+
+assert_default_eq!(bool, true);
+assert_default_eq!(u32, 1 + 1);
+
+fn main() {}
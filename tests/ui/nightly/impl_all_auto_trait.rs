@@ -0,0 +1,18 @@
+#![feature(auto_traits, negative_impls)]
+
+#[macro_use]
+extern crate static_assertions;
+
+auto trait Plain {}
+
+struct Handle(u32);
+
+impl !Plain for Handle {}
+
+struct Connection {
+    handle: Handle,
+}
+
+assert_impl_all!(Connection: Plain);
+
+fn main() {}
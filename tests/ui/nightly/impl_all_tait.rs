@@ -0,0 +1,20 @@
+#![feature(type_alias_impl_trait)]
+
+#[macro_use]
+extern crate static_assertions;
+
+use std::rc::Rc;
+
+type Local = impl Fn() -> u8;
+
+#[define_opaque(Local)]
+fn local() -> Local {
+    let rc = Rc::new(42);
+    move || *rc
+}
+
+assert_impl_all!(Local: Send);
+
+fn main() {
+    let _ = local();
+}
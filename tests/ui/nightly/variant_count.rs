@@ -0,0 +1,12 @@
+#[macro_use]
+extern crate static_assertions;
+
+enum Color {
+    Red,
+    Green,
+    Blue,
+}
+
+assert_variant_count!(Color, 4);
+
+fn main() {}
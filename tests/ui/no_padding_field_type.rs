@@ -0,0 +1,11 @@
+#[macro_use]
+extern crate static_assertions;
+
+struct S {
+    a: &'static [u8; 15],
+    b: u8,
+}
+
+assert_no_padding!(S { a: [u8; 15], b: u8 });
+
+fn main() {}
@@ -0,0 +1,6 @@
+#[macro_use]
+extern crate static_assertions;
+
+assert_not_impl_any!(u32: Into<u16>, Into<u64>);
+
+fn main() {}
@@ -0,0 +1,8 @@
+#[macro_use]
+extern crate static_assertions;
+
+// Not compiled with `cfg(test)`, so these are never checked.
+test_assert_impl_all!(String: Copy);
+test_assert_impl_all!(*const u8: Send, Sync; "raw pointers are not thread-safe");
+
+fn main() {}
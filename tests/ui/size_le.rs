@@ -0,0 +1,11 @@
+#[macro_use]
+extern crate static_assertions;
+
+struct Packet {
+    header: [u8; 8],
+    payload: [u8; 64],
+}
+
+assert_size_le!(Packet, 64, "packets must fit in a single frame");
+
+fn main() {}
@@ -0,0 +1,6 @@
+#[macro_use]
+extern crate static_assertions;
+
+assert_transmute_safe!(Vec<u8>, [usize; 3]);
+
+fn main() {}
@@ -0,0 +1,26 @@
+#![no_std]
+#![deny(unsafe_code)]
+
+#[macro_use]
+extern crate static_assertions;
+
+use core::marker::PhantomPinned;
+use core::pin::Pin;
+
+#[allow(dead_code)]
+struct Plain {
+    value: u32,
+}
+
+#[allow(dead_code)]
+struct SelfRef {
+    data: [u8; 16],
+    ptr: *const u8,
+    _pin: PhantomPinned,
+}
+
+assert_unpin!(Plain);
+assert_unpin!(u8, [u8], &'static SelfRef, Pin<&'static mut SelfRef>,);
+
+assert_not_unpin!(SelfRef);
+assert_not_unpin!(PhantomPinned, (u8, SelfRef), [SelfRef; 1],);
@@ -0,0 +1,40 @@
+#![no_std]
+#![deny(unsafe_code)]
+
+#[macro_use]
+extern crate static_assertions;
+
+use core::any::Any;
+use core::fmt::Debug;
+
+#[allow(dead_code)]
+trait Shape: Debug {
+    fn area(&self) -> u32;
+}
+
+#[allow(dead_code)]
+trait Polygon: Shape + Send {
+    fn sides(&self) -> usize;
+}
+
+#[allow(dead_code)]
+trait Square: Polygon + Any {}
+
+#[allow(dead_code)]
+trait Convert<T>: Shape {
+    fn convert(&self) -> T;
+}
+
+assert_upcast!(dyn Polygon -> dyn Shape);
+assert_upcast!(dyn Polygon -> dyn Debug);
+assert_upcast!(dyn Square -> dyn Shape);
+assert_upcast!(dyn Square -> dyn Any);
+assert_upcast!(dyn Polygon + Sync -> dyn Shape + Send + Sync);
+assert_upcast!(dyn Convert<u8> -> dyn Shape);
+assert_upcast!(dyn Shape + 'static -> dyn Debug);
+assert_upcast!(dyn Polygon -> dyn Polygon);
+
+#[test]
+fn test_upcast() {
+    assert_upcast!(dyn Square -> dyn Polygon);
+}
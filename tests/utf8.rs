@@ -0,0 +1,12 @@
+#![no_std]
+#![deny(unsafe_code)]
+
+#[macro_use]
+extern crate static_assertions;
+
+const ASCII: &[u8] = b"RIFF";
+const MULTI_BYTE: &[u8] = "Grüße, 世界".as_bytes();
+const ARRAY: [u8; 3] = [0xE2, 0x82, 0xAC];
+
+assert_valid_utf8!(ASCII);
+assert_valid_utf8!(MULTI_BYTE, &ARRAY, b"", &[],);
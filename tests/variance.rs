@@ -0,0 +1,34 @@
+#![no_std]
+#![deny(unsafe_code)]
+
+#[macro_use]
+extern crate static_assertions;
+
+extern crate alloc;
+
+use alloc::boxed::Box;
+use core::cell::Cell;
+use core::marker::PhantomData;
+
+#[allow(dead_code)]
+struct Token<'a> {
+    text: &'a str,
+    line: usize,
+}
+
+#[allow(dead_code)]
+struct Marker<'a, T>(PhantomData<&'a T>);
+
+#[allow(dead_code)]
+struct Slot<'a> {
+    value: Cell<&'a str>,
+}
+
+assert_covariant!(Token<'a>);
+assert_covariant!(Marker<'a, u8>);
+assert_covariant!(Option<&'a [u8]>);
+assert_covariant!((&'a str, &'static mut u8),);
+assert_covariant!(&'a Slot<'static>);
+assert_covariant!(Box<[&'a str]>);
+assert_covariant!(fn() -> &'a str);
+assert_covariant!(u8);
@@ -0,0 +1,22 @@
+#![cfg(feature = "nightly")]
+#![no_std]
+#![deny(unsafe_code)]
+
+#[macro_use]
+extern crate static_assertions;
+
+#[allow(dead_code)]
+enum Color {
+    Red,
+    Green,
+    Blue,
+}
+
+#[allow(dead_code)]
+enum Empty {}
+
+assert_variant_count!(Color, 3);
+assert_variant_count!(Option<u8>, 2,);
+assert_variant_count!(Empty, 0);
+assert_variant_count!(core::cmp::Ordering, 1 + 2);
+assert_variant_count!(Color, 3; Red, Green, Blue);